@@ -7,12 +7,18 @@
 #[macro_export]
 macro_rules! error_messages {
     {
-        $name:ident code: $code_pfx:literal, type: $message_pfx:literal,
-        $($error_name:ident $({ $($field:ident : $inner:ty),+ $(,)? })? = $code:literal: $body:literal),+ $(,)?
+        $name:ident code: $code_pfx:literal,
+        // errors with an underlying cause carry it as an `Option` context field named `source`
+        $(sources: [$($source_error:ident),+ $(,)?],)?
+        type: $message_pfx:literal,
+        // fields following a `;` are carried by the error, but are not part of its message
+        $($error_name:ident $({
+            $($field:ident : $inner:ty),* $(; $($context:ident : $context_inner:ty),+)? $(,)?
+        })? = $code:literal: $body:literal),+ $(,)?
     } => {
         #[derive(Clone, Eq, PartialEq)]
         pub enum $name {$(
-            $error_name$( { $($field: $inner),+ })?,
+            $error_name$( { $($field: $inner,)* $($($context: $context_inner),+)? })?,
         )*}
 
         impl $name {
@@ -20,7 +26,7 @@ macro_rules! error_messages {
 
             pub const fn code(&self) -> usize {
                 match self {$(
-                    Self::$error_name $({ $($field: _),+ })? => $code,
+                    Self::$error_name $({ $($field: _,)* .. })? => $code,
                 )*}
             }
 
@@ -30,7 +36,7 @@ macro_rules! error_messages {
 
             pub fn message(&self) -> String {
                 match self {$(
-                    Self::$error_name $({$($field),+})? => format!($body $($(, $field = $field)+)?),
+                    Self::$error_name $({ $($field,)* .. })? => format!($body $($(, $field = $field)*)?),
                 )*}
             }
 
@@ -56,7 +62,7 @@ macro_rules! error_messages {
 
             const fn name(&self) -> &'static str {
                 match self {$(
-                    Self::$error_name $({ $($field: _),+ })? => concat!(stringify!($name), "::", stringify!($error_name)),
+                    Self::$error_name $({ $($field: _,)* .. })? => concat!(stringify!($name), "::", stringify!($error_name)),
                 )*}
             }
         }
@@ -79,8 +85,9 @@ macro_rules! error_messages {
                 debug_struct.field("message", &format!("{}", self));
                 $(
                     $(
-                        if let Self::$error_name { $($field),+ } = &self {
-                            $(debug_struct.field(stringify!($field), &$field);)+
+                        if let Self::$error_name { $($field,)* $($($context),+)? } = &self {
+                            $(debug_struct.field(stringify!($field), &$field);)*
+                            $($(debug_struct.field(stringify!($context), &$context);)+)?
                         }
                     )?
                 )*
@@ -90,7 +97,12 @@ macro_rules! error_messages {
 
         impl std::error::Error for $name {
             fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-                None
+                match self {
+                    $($(Self::$source_error { source, .. } => {
+                        source.as_ref().map(|source| source as &(dyn std::error::Error + 'static))
+                    })+)?
+                    _ => None,
+                }
             }
         }
     };
@@ -99,14 +111,31 @@ macro_rules! error_messages {
 #[allow(dead_code)]
 #[cfg(test)]
 mod tests {
+    use std::{error::Error, num::ParseIntError};
+
     error_messages! { TestError
-        code: "TST", type: "Test Error",
+        code: "TST",
+        sources: [ErrorWithSource],
+        type: "Test Error",
         BasicError =
             1: "This is a basic error.",
         ErrorWithAttributes { int: i32, string: String } =
             2: "This is an error with i32 {int} and string '{string}'.",
         MultiLine =
-            3: "This is an error,\nthat spans,\nmultiple lines."
+            3: "This is an error,\nthat spans,\nmultiple lines.",
+        ErrorWithContext { int: i32; context: String } =
+            4: "This is an error with i32 {int} and unformatted context.",
+        ErrorWithSource { string: String; source: Option<ParseIntError> } =
+            5: "This is an error with string '{string}' and a source.",
+    }
+
+    #[test]
+    pub fn source_is_exposed() {
+        let source = "x".parse::<i32>().unwrap_err();
+        let error = TestError::ErrorWithSource { string: "x".to_owned(), source: Some(source.clone()) };
+        assert_eq!(error.source().map(ToString::to_string), Some(source.to_string()));
+        assert!(TestError::ErrorWithSource { string: "x".to_owned(), source: None }.source().is_none());
+        assert!(TestError::BasicError.source().is_none());
     }
 
     #[test]
@@ -115,6 +144,7 @@ mod tests {
             TestError::BasicError,
             TestError::ErrorWithAttributes { int: 1, string: "error message".to_string() },
             TestError::MultiLine,
+            TestError::ErrorWithContext { int: 1, context: "error context".to_string() },
         ];
 
         for error in errors {
@@ -4,12 +4,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::{error::Error as StdError, fmt};
+use std::{error::Error as StdError, fmt, num::ParseIntError, ops::RangeInclusive};
 
 use itertools::Itertools;
 use pest::error::{Error as PestError, LineColLocation};
 
-use crate::{error_messages, util::write_joined};
+use crate::{common::Span, error_messages, util::write_joined};
 
 #[macro_use]
 mod macros;
@@ -22,7 +22,15 @@ pub struct Error {
     errors: Vec<TypeQLError>,
 }
 
-impl StdError for Error {}
+impl StdError for Error {
+    /// The cause of a single error. The messages of the errors themselves are part of this error's `Display`.
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.errors[..] {
+            [error] => error.source(),
+            _ => None,
+        }
+    }
+}
 
 impl From<TypeQLError> for Error {
     fn from(error: TypeQLError) -> Self {
@@ -74,7 +82,9 @@ pub fn collect_err(i: impl IntoIterator<Item = Result<(), Error>>) -> Result<(),
 }
 
 error_messages! { TypeQLError
-    code: "TQL", type: "TypeQL Error",
+    code: "TQL",
+    sources: [ExponentOutOfRange, IntegerOutOfRange],
+    type: "TypeQL Error",
     SyntaxErrorDetailed { error_line_nr: usize, error_col: usize, formatted_error: String } =
         3: "There is a syntax error at {error_line_nr}:{error_col}:\n{formatted_error}",
     InvalidCasting { enum_name: &'static str, variant: &'static str, expected_variant: &'static str, typename: &'static str } =
@@ -153,4 +163,78 @@ error_messages! { TypeQLError
         37: "Illegal grammar: '{input}'",
     InvalidTypeLabel { label: String } =
         38: "The type label '{label}' is invalid. Type labels must be valid utf-8 identifiers without a leading underscore.",
+    LeapSecondNotAllowed { ; span: Option<Span> } =
+        39: "Leap seconds (a second value of '60') are not allowed by the validation options.",
+    InvalidTime { time: String } =
+        40: "The time '{time}' is not a valid time of day.",
+    InvalidDate { date: String } =
+        41: "The date '{date}' is not a valid calendar date.",
+    InvalidTimeZoneOffset { offset: String } =
+        42: "The time zone offset '{offset}' is out of range.",
+    UnknownTimeZone { name: String } =
+        43: "The time zone '{name}' is not a known IANA time zone.",
+    AmbiguousLocalTime { date_time: String, time_zone: String } =
+        44: "The local time '{date_time}' is ambiguous in time zone '{time_zone}', as it occurs twice around a daylight saving transition.",
+    NonexistentLocalTime { date_time: String, time_zone: String } =
+        45: "The local time '{date_time}' does not exist in time zone '{time_zone}', as it is skipped by a daylight saving transition.",
+    InputTooComplex { limit: usize } =
+        46: "The input exceeds the configured limit of {limit} syntax tree nodes.",
+    UnknownUnitSuffix { literal: String, suffix: String } =
+        47: "The integer '{literal}' has an unknown unit suffix '{suffix}'. The supported suffixes are 'k', 'M', 'G' and 'T'.",
+    UnexpectedLiteralKind { literal: String, kind: String } =
+        48: "'{literal}' is not a valid {kind} literal.",
+    ExponentOutOfRange { decimal: String; source: Option<ParseIntError> } =
+        49: "The exponent of the decimal '{decimal}' is outside the range of a 32-bit integer.",
+    YearOutOfRange { year: String; span: Option<Span> } =
+        50: "The year '{year}' is outside the range allowed by the validation options.",
+    InvalidValueLiteral { literal: String; span: Option<Span> } =
+        51: "'{literal}' is not a valid value literal.",
+    RoundTripMismatch { literal: String, reparsed: String } =
+        52: "The literal '{literal}' does not survive a display and parse round trip, and parses back as {reparsed}.",
+    InvalidWeekDate { literal: String } =
+        53: "'{literal}' is not a valid ISO week date: the week must exist in the week year and the weekday be 1 to 7.",
+    IntegerOutOfRange { integer: String; source: Option<ParseIntError> } =
+        54: "The integer '{integer}' is outside the range of a 128-bit integer.",
+    DuplicateTimeZone { literal: String; span: Option<Span> } =
+        55: "The datetime '{literal}' has more than one time zone.",
+    InvalidOrdinalDate { literal: String } =
+        56: "'{literal}' is not a valid ISO ordinal date: the day must be 1 to 365, or 366 in a leap year.",
+    SubsecondPrecision { literal: String; span: Option<Span> } =
+        57: "The datetime '{literal}' has a fractional second, which whole-second storage truncates.",
+    StringLiteralTooLong { len: usize, max: usize; span: Option<Span> } =
+        58: "The string literal of {len} bytes exceeds the configured limit of {max} bytes.",
+    IllegalCoercion { literal: String, value_type: String; span: Option<Span> } =
+        59: "The string {literal} cannot be cast to {value_type}, as its content is not a {value_type} literal.",
+    InvalidUtf8 { position: usize } =
+        60: "The input is not valid UTF-8, from byte {position}.",
+    InconsistentSeparators { literal: String; span: Option<Span> } =
+        61: "The number '{literal}' mixes '_' and ',' separators, so its grouping and decimal point are ambiguous.",
+    EmptyNumericLiteral { ; span: Option<Span> } =
+        62: "A number has a sign but no digits.",
+    UnsupportedIANATimeZone { name: String } =
+        63: "The IANA time zone '{name}' cannot be resolved by the 'time' crate: convert with the 'chrono-tz' feature instead.",
+    NonFiniteDecimal { literal: String; span: Option<Span> } =
+        64: "'{literal}' is not a number: decimals must be finite, as infinities and NaN are not supported.",
+    EpochOutOfRange { seconds: String } =
+        65: "The epoch timestamp of {seconds} seconds is outside the range of a datetime.",
+    MixedRangeTypes { start: String, end: String; span: Option<Span> } =
+        66: "The bounds of a range must have the same value type, but the start is a {start} and the end is a {end}.",
+    ValueTypeMismatch { expected: String, found: String; span: Option<Span> } =
+        67: "Expected a value of type '{expected}', but found a value of type '{found}'.",
+    InvalidColumnValue { index: usize, expected: String, literal: String; span: Option<Span> } =
+        68: "The literal '{literal}' at index {index} is not of the column's type '{expected}', or is out of its range.",
+    ComponentOutOfRange { component: &'static str, value: i64, range: RangeInclusive<i64>; span: Option<Span> } =
+        69: "The {component} '{value}' is outside its range of {range:?}.",
+    UnsupportedSqlValue { value_type: String, dialect: String } =
+        70: "A {value_type} literal cannot be written as a SQL literal in the {dialect} dialect.",
+    FractionTooPrecise { precision: usize, max: usize; span: Option<Span> } =
+        71: "A time has {precision} fractional second digits, but at most {max} are allowed by the validation options.",
+    UnsupportedJsonValue { kind: String } =
+        72: "A JSON {kind} cannot be converted to a literal; only strings, numbers and booleans can.",
+    NumberOutOfRange { number: String, target: &'static str; span: Option<Span> } =
+        73: "The number '{number}' is outside the range of {target}.",
+    MisdelimitedTimeZone { literal: String, zone: String; span: Option<Span> } =
+        74: "The time zone '{zone}' of the datetime '{literal}' is wrongly delimited: an IANA name follows the time after a space, as in '10:00 Europe/London', an ISO offset follows it directly, as in '10:00+01:00', and neither is bracketed.",
+    InputTooLong { len: usize, max: usize } =
+        75: "The input of {len} bytes exceeds the configured limit of {max} bytes.",
 }
@@ -17,11 +17,26 @@ pub mod token;
 pub type Result<T = ()> = std::result::Result<T, Error>;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct LineColumn {
     pub line: u32,
     pub column: u32,
 }
 
+impl LineColumn {
+    /// This position within a fragment, as a position within a larger input in which the fragment begins at `origin`.
+    /// Positions saturate rather than overflow. Lines and columns count from 1, so a position with a zero line or column
+    /// has no place in the fragment and gives `None`.
+    pub fn offset_to(self, origin: LineColumn) -> Option<LineColumn> {
+        let (line, column) = (self.line.checked_sub(1)?, self.column.checked_sub(1)?);
+        match line {
+            0 => Some(LineColumn { line: origin.line, column: origin.column.saturating_add(column) }),
+            line => Some(LineColumn { line: origin.line.saturating_add(line), column: self.column }),
+        }
+    }
+}
+
 impl Display for LineColumn {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}:{}", self.line, self.column)
@@ -29,11 +44,21 @@ impl Display for LineColumn {
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Span {
     pub begin: LineColumn,
     pub end: LineColumn,
 }
 
+impl Span {
+    /// This span within a fragment, as a span within a larger input in which the fragment begins at `origin`, or `None`
+    /// if either end has a zero line or column.
+    pub fn offset_to(self, origin: LineColumn) -> Option<Span> {
+        Some(Span { begin: self.begin.offset_to(origin)?, end: self.end.offset_to(origin)? })
+    }
+}
+
 pub trait Spanned {
     fn span(&self) -> Option<Span>;
 }
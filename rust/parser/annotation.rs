@@ -6,7 +6,7 @@
 
 use super::{
     literal::{visit_integer_literal, visit_quoted_string_literal, visit_value_literal},
-    visit_identifier, IntoChildNodes, Node, Rule, RuleMatcher,
+    visit_identifier, IntoChildNodes, Node, ParserOptions, Rule, RuleMatcher,
 };
 use crate::{
     annotation::{
@@ -17,12 +17,12 @@ use crate::{
     value::Literal,
 };
 
-pub(super) fn visit_annotations(node: Node<'_>) -> Vec<Annotation> {
+pub(super) fn visit_annotations(node: Node<'_>, options: &ParserOptions) -> Vec<Annotation> {
     debug_assert_eq!(node.as_rule(), Rule::annotations);
-    node.into_children().map(visit_annotation).collect()
+    node.into_children().map(|node| visit_annotation(node, options)).collect()
 }
 
-fn visit_annotation(node: Node<'_>) -> Annotation {
+fn visit_annotation(node: Node<'_>, options: &ParserOptions) -> Annotation {
     debug_assert_eq!(node.as_rule(), Rule::annotation);
     let span = node.span();
     let child = node.into_child();
@@ -34,10 +34,10 @@ fn visit_annotation(node: Node<'_>) -> Annotation {
         Rule::ANNOTATION_KEY => Annotation::Key(Key::new(span)),
         Rule::ANNOTATION_UNIQUE => Annotation::Unique(Unique::new(span)),
         Rule::annotation_card => Annotation::Cardinality(visit_annotation_card(child)),
-        Rule::annotation_range => Annotation::Range(visit_annotation_range(child)),
+        Rule::annotation_range => Annotation::Range(visit_annotation_range(child, options)),
         Rule::annotation_regex => Annotation::Regex(visit_annotation_regex(child)),
         Rule::annotation_subkey => Annotation::Subkey(visit_annotation_subkey(child)),
-        Rule::annotation_values => Annotation::Values(visit_annotation_values(child)),
+        Rule::annotation_values => Annotation::Values(visit_annotation_values(child, options)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
@@ -71,47 +71,48 @@ fn visit_cardinality_range(node: Node<'_>) -> CardinalityRange {
     CardinalityRange::Range(min, max)
 }
 
-fn visit_annotation_range(node: Node<'_>) -> Range {
+fn visit_annotation_range(node: Node<'_>, options: &ParserOptions) -> Range {
     debug_assert_eq!(node.as_rule(), Rule::annotation_range);
     let span = node.span();
     let mut children = node.into_children();
-    let (lower, upper) = visit_range(children.skip_expected(Rule::ANNOTATION_RANGE).consume_expected(Rule::range));
+    let (lower, upper) =
+        visit_range(children.skip_expected(Rule::ANNOTATION_RANGE).consume_expected(Rule::range), options);
     debug_assert_eq!(children.try_consume_any(), None);
     Range::new(span, lower, upper)
 }
 
-fn visit_range(node: Node<'_>) -> (Option<Literal>, Option<Literal>) {
+fn visit_range(node: Node<'_>, options: &ParserOptions) -> (Option<Literal>, Option<Literal>) {
     debug_assert_eq!(node.as_rule(), Rule::range);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::range_full => visit_range_full(child),
-        Rule::range_from => visit_range_from(child),
-        Rule::range_to => visit_range_to(child),
+        Rule::range_full => visit_range_full(child, options),
+        Rule::range_from => visit_range_from(child, options),
+        Rule::range_to => visit_range_to(child, options),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-fn visit_range_full(node: Node<'_>) -> (Option<Literal>, Option<Literal>) {
+fn visit_range_full(node: Node<'_>, options: &ParserOptions) -> (Option<Literal>, Option<Literal>) {
     debug_assert_eq!(node.as_rule(), Rule::range_full);
     let mut children = node.into_children();
-    let lower = visit_value_literal(children.consume_expected(Rule::value_literal));
-    let upper = visit_value_literal(children.consume_expected(Rule::value_literal));
+    let lower = visit_value_literal(children.consume_expected(Rule::query_value_literal), options);
+    let upper = visit_value_literal(children.consume_expected(Rule::query_value_literal), options);
     debug_assert_eq!(children.try_consume_any(), None);
     (Some(lower), Some(upper))
 }
 
-fn visit_range_from(node: Node<'_>) -> (Option<Literal>, Option<Literal>) {
+fn visit_range_from(node: Node<'_>, options: &ParserOptions) -> (Option<Literal>, Option<Literal>) {
     debug_assert_eq!(node.as_rule(), Rule::range_from);
     let mut children = node.into_children();
-    let lower = visit_value_literal(children.consume_expected(Rule::value_literal));
+    let lower = visit_value_literal(children.consume_expected(Rule::query_value_literal), options);
     debug_assert_eq!(children.try_consume_any(), None);
     (Some(lower), None)
 }
 
-fn visit_range_to(node: Node<'_>) -> (Option<Literal>, Option<Literal>) {
+fn visit_range_to(node: Node<'_>, options: &ParserOptions) -> (Option<Literal>, Option<Literal>) {
     debug_assert_eq!(node.as_rule(), Rule::range_to);
     let mut children = node.into_children();
-    let upper = visit_value_literal(children.consume_expected(Rule::value_literal));
+    let upper = visit_value_literal(children.consume_expected(Rule::query_value_literal), options);
     debug_assert_eq!(children.try_consume_any(), None);
     (None, Some(upper))
 }
@@ -136,9 +137,13 @@ fn visit_annotation_regex(node: Node<'_>) -> Regex {
     Regex::new(span, regex)
 }
 
-fn visit_annotation_values(node: Node<'_>) -> Values {
+fn visit_annotation_values(node: Node<'_>, options: &ParserOptions) -> Values {
     debug_assert_eq!(node.as_rule(), Rule::annotation_values);
     let span = node.span();
-    let values = node.into_children().skip_expected(Rule::ANNOTATION_VALUES).map(visit_value_literal).collect();
+    let values = node
+        .into_children()
+        .skip_expected(Rule::ANNOTATION_VALUES)
+        .map(|node| visit_value_literal(node, options))
+        .collect();
     Values::new(span, values)
 }
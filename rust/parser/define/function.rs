@@ -11,7 +11,7 @@ use crate::{
     parser::{
         pipeline::{visit_query_stage, visit_reducer},
         type_::visit_named_type_any,
-        visit_identifier, visit_var, visit_vars, IntoChildNodes, Node, Rule, RuleMatcher,
+        visit_identifier, visit_var, visit_vars, IntoChildNodes, Node, ParserOptions, Rule, RuleMatcher,
     },
     schema::definable::{
         function::{
@@ -22,23 +22,26 @@ use crate::{
     },
 };
 
-pub(in crate::parser) fn visit_definition_function(node: Node<'_>) -> Function {
+pub(in crate::parser) fn visit_definition_function(node: Node<'_>, options: &ParserOptions) -> Function {
     debug_assert_eq!(node.as_rule(), Rule::definition_function);
     let span = node.span();
     let mut children = node.into_children();
 
     children.skip_expected(Rule::FUN);
     let signature = visit_function_signature(children.consume_expected(Rule::function_signature));
-    let block = visit_function_block(children.consume_expected(Rule::function_block));
+    let block = visit_function_block(children.consume_expected(Rule::function_block), options);
     debug_assert_eq!(children.try_consume_any(), None);
     Function::new(span, signature, block)
 }
 
-pub fn visit_function_block(node: Node<'_>) -> FunctionBlock {
+pub fn visit_function_block(node: Node<'_>, options: &ParserOptions) -> FunctionBlock {
     debug_assert_eq!(node.as_rule(), Rule::function_block);
     let span = node.span();
     let mut children = node.into_children();
-    let stages = children.take_while_ref(|node| node.as_rule() == Rule::query_stage).map(visit_query_stage).collect();
+    let stages = children
+        .take_while_ref(|node| node.as_rule() == Rule::query_stage)
+        .map(|node| visit_query_stage(node, options))
+        .collect();
 
     let return_stmt = visit_return_statement(children.consume_expected(Rule::return_statement));
     debug_assert_eq!(children.try_consume_any(), None);
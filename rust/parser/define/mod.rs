@@ -5,7 +5,7 @@
  */
 
 use self::{function::visit_definition_function, struct_::visit_definition_struct, type_::visit_definition_type};
-use super::{IntoChildNodes, Node, Rule, RuleMatcher};
+use super::{IntoChildNodes, Node, ParserOptions, Rule, RuleMatcher};
 use crate::{
     common::{error::TypeQLError, Spanned},
     query::schema::Define,
@@ -16,27 +16,27 @@ pub(super) mod function;
 pub(super) mod struct_;
 pub(super) mod type_;
 
-pub(super) fn visit_query_define(node: Node<'_>) -> Define {
+pub(super) fn visit_query_define(node: Node<'_>, options: &ParserOptions) -> Define {
     debug_assert_eq!(node.as_rule(), Rule::query_define);
     let span = node.span();
     let mut children = node.into_children();
     children.skip_expected(Rule::DEFINE);
-    let definables = visit_definables(children.consume_expected(Rule::definables));
+    let definables = visit_definables(children.consume_expected(Rule::definables), options);
     debug_assert_eq!(children.try_consume_any(), None);
     Define::new(span, definables)
 }
 
-pub(super) fn visit_definables(node: Node<'_>) -> Vec<Definable> {
+pub(super) fn visit_definables(node: Node<'_>, options: &ParserOptions) -> Vec<Definable> {
     debug_assert_eq!(node.as_rule(), Rule::definables);
-    node.into_children().map(visit_definable).collect()
+    node.into_children().map(|node| visit_definable(node, options)).collect()
 }
 
-pub(super) fn visit_definable(node: Node<'_>) -> Definable {
+pub(super) fn visit_definable(node: Node<'_>, options: &ParserOptions) -> Definable {
     debug_assert_eq!(node.as_rule(), Rule::definable);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::definition_type => Definable::TypeDeclaration(visit_definition_type(child)),
-        Rule::definition_function => Definable::Function(visit_definition_function(child)),
+        Rule::definition_type => Definable::TypeDeclaration(visit_definition_type(child, options)),
+        Rule::definition_function => Definable::Function(visit_definition_function(child, options)),
         Rule::definition_struct => Definable::Struct(visit_definition_struct(child)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
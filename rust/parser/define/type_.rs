@@ -9,7 +9,7 @@ use crate::{
     parser::{
         annotation::visit_annotations,
         type_::{visit_label, visit_label_list, visit_label_scoped, visit_value_type},
-        visit_kind, IntoChildNodes, Node, Rule, RuleMatcher,
+        visit_kind, IntoChildNodes, Node, ParserOptions, Rule, RuleMatcher,
     },
     schema::definable::type_::{
         capability::{Alias, Owns, Plays, Relates, Sub, ValueType},
@@ -18,23 +18,29 @@ use crate::{
     type_::{NamedType, TypeRef, TypeRefAny},
 };
 
-pub(super) fn visit_definition_type(node: Node<'_>) -> Type {
+pub(super) fn visit_definition_type(node: Node<'_>, options: &ParserOptions) -> Type {
     debug_assert_eq!(node.as_rule(), Rule::definition_type);
     let span = node.span();
     let mut children = node.into_children();
     let kind = children.try_consume_expected(Rule::kind).map(visit_kind);
     let label = visit_label(children.consume_expected(Rule::label));
-    let annotations = children.try_consume_expected(Rule::annotations).map(visit_annotations).unwrap_or_default();
-    let traits = children.map(visit_type_capability).collect();
+    let annotations = children
+        .try_consume_expected(Rule::annotations)
+        .map(|node| visit_annotations(node, options))
+        .unwrap_or_default();
+    let traits = children.map(|node| visit_type_capability(node, options)).collect();
     Type::new(span, kind, label, annotations, traits)
 }
 
-pub(in crate::parser) fn visit_type_capability(node: Node<'_>) -> Capability {
+pub(in crate::parser) fn visit_type_capability(node: Node<'_>, options: &ParserOptions) -> Capability {
     debug_assert_eq!(node.as_rule(), Rule::type_capability);
     let span = node.span();
     let mut children = node.into_children();
     let base = visit_type_capability_base(children.consume_expected(Rule::type_capability_base));
-    let annotations = children.try_consume_expected(Rule::annotations).map(visit_annotations).unwrap_or_default();
+    let annotations = children
+        .try_consume_expected(Rule::annotations)
+        .map(|node| visit_annotations(node, options))
+        .unwrap_or_default();
     debug_assert_eq!(children.try_consume_any(), None);
     Capability::new(span, base, annotations)
 }
@@ -6,7 +6,9 @@
 
 use pest::pratt_parser::{Assoc, Op, PrattParser};
 
-use super::{literal::visit_value_literal, visit_identifier, visit_var, IntoChildNodes, Node, Rule, RuleMatcher};
+use super::{
+    literal::visit_value_literal, visit_identifier, visit_var, IntoChildNodes, Node, ParserOptions, Rule, RuleMatcher,
+};
 use crate::{
     common::{error::TypeQLError, token, Spanned},
     expression::{
@@ -15,33 +17,35 @@ use crate::{
     value::{Literal, StructLiteral, ValueLiteral},
 };
 
-pub(super) fn visit_expression_function(node: Node<'_>) -> FunctionCall {
+pub(super) fn visit_expression_function(node: Node<'_>, options: &ParserOptions) -> FunctionCall {
     debug_assert_eq!(node.as_rule(), Rule::expression_function);
     let span = node.span();
     let mut children = node.into_children();
     let name = visit_expression_function_name(children.consume_expected(Rule::expression_function_name));
-    let args =
-        children.try_consume_expected(Rule::expression_arguments).map(visit_expression_arguments).unwrap_or_default();
+    let args = children
+        .try_consume_expected(Rule::expression_arguments)
+        .map(|node| visit_expression_arguments(node, options))
+        .unwrap_or_default();
     debug_assert_eq!(children.try_consume_any(), None);
     FunctionCall::new(span, name, args)
 }
 
-fn visit_expression_arguments(node: Node<'_>) -> Vec<Expression> {
+fn visit_expression_arguments(node: Node<'_>, options: &ParserOptions) -> Vec<Expression> {
     debug_assert_eq!(node.as_rule(), Rule::expression_arguments);
-    node.into_children().map(visit_expression).collect()
+    node.into_children().map(|node| visit_expression(node, options)).collect()
 }
 
-pub(super) fn visit_expression(node: Node<'_>) -> Expression {
+pub(super) fn visit_expression(node: Node<'_>, options: &ParserOptions) -> Expression {
     debug_assert_eq!(node.as_rule(), Rule::expression);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::expression_value => visit_expression_value(child),
-        Rule::expression_list => visit_expression_list(child),
+        Rule::expression_value => visit_expression_value(child, options),
+        Rule::expression_list => visit_expression_list(child, options),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-pub(super) fn visit_expression_value(node: Node<'_>) -> Expression {
+pub(super) fn visit_expression_value(node: Node<'_>, options: &ParserOptions) -> Expression {
     debug_assert_eq!(node.as_rule(), Rule::expression_value);
 
     let pratt_parser: PrattParser<Rule> = PrattParser::new()
@@ -52,7 +56,7 @@ pub(super) fn visit_expression_value(node: Node<'_>) -> Expression {
         .op(Op::infix(Rule::POWER, Assoc::Right));
 
     pratt_parser
-        .map_primary(visit_expression_base)
+        .map_primary(|node| visit_expression_base(node, options))
         .map_infix(|left, op, right| {
             let op = match op.as_rule() {
                 Rule::PLUS => token::ArithmeticOperator::Add,
@@ -68,31 +72,31 @@ pub(super) fn visit_expression_value(node: Node<'_>) -> Expression {
         .parse(node.into_children())
 }
 
-fn visit_expression_base(node: Node<'_>) -> Expression {
+fn visit_expression_base(node: Node<'_>, options: &ParserOptions) -> Expression {
     debug_assert_eq!(node.as_rule(), Rule::expression_base);
     let child = node.into_child();
     match child.as_rule() {
         Rule::var => Expression::Variable(visit_var(child)),
-        Rule::value_literal => Expression::Value(visit_value_literal(child)),
-        Rule::expression_function => Expression::Function(visit_expression_function(child)),
-        Rule::expression_parenthesis => Expression::Paren(Box::new(visit_expression_parenthesis(child))),
-        Rule::expression_list_index => Expression::ListIndex(Box::new(visit_expression_list_index(child))),
+        Rule::query_value_literal => Expression::Value(visit_value_literal(child, options)),
+        Rule::expression_function => Expression::Function(visit_expression_function(child, options)),
+        Rule::expression_parenthesis => Expression::Paren(Box::new(visit_expression_parenthesis(child, options))),
+        Rule::expression_list_index => Expression::ListIndex(Box::new(visit_expression_list_index(child, options))),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-fn visit_expression_list_index(node: Node<'_>) -> ListIndex {
+fn visit_expression_list_index(node: Node<'_>, options: &ParserOptions) -> ListIndex {
     debug_assert_eq!(node.as_rule(), Rule::expression_list_index);
     let span = node.span();
     let mut children = node.into_children();
     let variable = visit_var(children.consume_expected(Rule::var));
-    let index = visit_list_index(children.consume_expected(Rule::list_index));
+    let index = visit_list_index(children.consume_expected(Rule::list_index), options);
     ListIndex::new(span, variable, index)
 }
 
-fn visit_list_index(node: Node<'_>) -> Expression {
+fn visit_list_index(node: Node<'_>, options: &ParserOptions) -> Expression {
     debug_assert_eq!(node.as_rule(), Rule::list_index);
-    visit_expression_value(node.into_child())
+    visit_expression_value(node.into_child(), options)
 }
 
 pub(super) fn visit_expression_struct(node: Node<'_>) -> Literal {
@@ -101,44 +105,44 @@ pub(super) fn visit_expression_struct(node: Node<'_>) -> Literal {
     // TODO parse properly
 }
 
-fn visit_expression_parenthesis(node: Node<'_>) -> Paren {
+fn visit_expression_parenthesis(node: Node<'_>, options: &ParserOptions) -> Paren {
     debug_assert_eq!(node.as_rule(), Rule::expression_parenthesis);
-    Paren::new(node.span(), visit_expression_value(node.into_child()))
+    Paren::new(node.span(), visit_expression_value(node.into_child(), options))
 }
 
-pub(super) fn visit_expression_list(node: Node<'_>) -> Expression {
+pub(super) fn visit_expression_list(node: Node<'_>, options: &ParserOptions) -> Expression {
     debug_assert_eq!(node.as_rule(), Rule::expression_list);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::expression_list_new => visit_expression_list_new(child),
-        Rule::expression_list_subrange => visit_expression_list_subrange(child),
+        Rule::expression_list_new => visit_expression_list_new(child, options),
+        Rule::expression_list_subrange => visit_expression_list_subrange(child, options),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-fn visit_expression_list_subrange(node: Node<'_>) -> Expression {
+fn visit_expression_list_subrange(node: Node<'_>, options: &ParserOptions) -> Expression {
     debug_assert_eq!(node.as_rule(), Rule::expression_list_subrange);
     let span = node.span();
     let mut children = node.into_children();
     let var = visit_var(children.consume_expected(Rule::var));
-    let (from, to) = visit_list_range(children.consume_expected(Rule::list_range));
+    let (from, to) = visit_list_range(children.consume_expected(Rule::list_range), options);
     debug_assert_eq!(children.try_consume_any(), None);
     Expression::ListIndexRange(Box::new(ListIndexRange::new(span, var, from, to)))
 }
 
-fn visit_list_range(node: Node<'_>) -> (Expression, Expression) {
+fn visit_list_range(node: Node<'_>, options: &ParserOptions) -> (Expression, Expression) {
     debug_assert_eq!(node.as_rule(), Rule::list_range);
     let mut children = node.into_children();
-    let from = visit_expression_value(children.consume_expected(Rule::expression_value));
-    let to = visit_expression_value(children.consume_expected(Rule::expression_value));
+    let from = visit_expression_value(children.consume_expected(Rule::expression_value), options);
+    let to = visit_expression_value(children.consume_expected(Rule::expression_value), options);
     debug_assert_eq!(children.try_consume_any(), None);
     (from, to)
 }
 
-fn visit_expression_list_new(node: Node<'_>) -> Expression {
+fn visit_expression_list_new(node: Node<'_>, options: &ParserOptions) -> Expression {
     debug_assert_eq!(node.as_rule(), Rule::expression_list_new);
     let span = node.span();
-    let items = node.into_children().map(visit_expression_value).collect();
+    let items = node.into_children().map(|node| visit_expression_value(node, options)).collect();
     Expression::List(List::new(span, items))
 }
 
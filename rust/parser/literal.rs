@@ -4,34 +4,190 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::str::FromStr;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use pest::Parser;
+
 use crate::{
-    common::{error::TypeQLError, Spanned},
-    parser::{IntoChildNodes, Node, Rule, RuleMatcher},
+    common::{error::TypeQLError, Error, Spanned},
+    parser::{
+        try_parse_single_with_options, DateOrder, IntoChildNodes, Node, ParserOptions, Rule, RuleMatcher, TypeQLParser,
+        Version,
+    },
     value::{
         BooleanLiteral, DateFragment, DateLiteral, DateTimeLiteral, DateTimeTZLiteral, DurationDate, DurationLiteral,
-        DurationTime, IntegerLiteral, Literal, NumericLiteral, Sign, SignedDecimalLiteral, SignedIntegerLiteral,
-        StringLiteral, TimeFragment, TimeZone, ValueLiteral,
+        DurationTime, IntegerLiteral, Literal, NullLiteral, NumericLiteral, RangeLiteral, Sign, SignedDecimalLiteral,
+        SignedIntegerLiteral, StringLiteral, TimeFragment, TimeZone, ValueLiteral, ValueType,
     },
+    Result,
 };
 
-pub(super) fn visit_value_literal(node: Node<'_>) -> Literal {
-    debug_assert_eq!(node.as_rule(), Rule::value_literal);
+/// The type of value parsed from a node of the given rule, for the rules that make up a `value_literal`. The literal
+/// visitor dispatches on this, so it is the one mapping from rules to value types.
+pub fn rule_to_value_type(rule: Rule) -> Option<ValueType> {
+    match rule {
+        Rule::quoted_string_literal | Rule::block_string_literal => Some(ValueType::String),
+        Rule::boolean_literal => Some(ValueType::Boolean),
+        Rule::signed_integer => Some(ValueType::Integer),
+        Rule::signed_decimal => Some(ValueType::Decimal),
+
+        Rule::datetime_tz_literal => Some(ValueType::DateTimeTz),
+        Rule::datetime_literal => Some(ValueType::DateTime),
+        Rule::date_literal => Some(ValueType::Date),
+        Rule::duration_literal => Some(ValueType::Duration),
+        Rule::timezone_literal => Some(ValueType::TimeZone),
+
+        _ => None,
+    }
+}
+
+/// Visits a `value_literal` or a `query_value_literal`, whose alternatives are the same but for standalone time zones.
+pub(super) fn visit_value_literal(node: Node<'_>, options: &ParserOptions) -> Literal {
+    debug_assert!(matches!(node.as_rule(), Rule::value_literal | Rule::query_value_literal));
     let span = node.span();
     let child = node.into_child();
-    let value_literal = match child.as_rule() {
-        Rule::quoted_string_literal => ValueLiteral::String(visit_quoted_string_literal(child)),
-        Rule::boolean_literal => ValueLiteral::Boolean(BooleanLiteral { value: child.as_str().to_owned() }),
-        Rule::signed_integer => ValueLiteral::Integer(visit_signed_integer(child)),
-        Rule::signed_decimal => ValueLiteral::Decimal(visit_signed_decimal(child)),
+    let value_literal = match rule_to_value_type(child.as_rule()) {
+        Some(ValueType::String) => ValueLiteral::String(visit_string_literal(child)),
+        Some(ValueType::Boolean) => ValueLiteral::Boolean(visit_boolean_literal(child)),
+        Some(ValueType::Integer) => ValueLiteral::Integer(visit_signed_integer(child)),
+        Some(ValueType::Decimal) => ValueLiteral::Decimal(visit_signed_decimal(child)),
 
-        Rule::datetime_tz_literal => ValueLiteral::DateTimeTz(visit_datetime_tz_literal(child)),
-        Rule::datetime_literal => ValueLiteral::DateTime(visit_datetime_literal(child)),
-        Rule::date_literal => ValueLiteral::Date(visit_date_literal(child)),
-        Rule::duration_literal => ValueLiteral::Duration(visit_duration_literal(child)),
+        Some(ValueType::DateTimeTz) => ValueLiteral::DateTimeTz(visit_datetime_tz_literal(child)),
+        Some(ValueType::DateTime) => ValueLiteral::DateTime(visit_datetime_literal(child)),
+        Some(ValueType::Date) => ValueLiteral::Date(visit_date_literal(child)),
+        Some(ValueType::Duration) => ValueLiteral::Duration(visit_duration_literal(child)),
+        Some(ValueType::TimeZone) => ValueLiteral::TimeZone(visit_timezone(child.into_child())),
 
+        Some(ValueType::Range | ValueType::Struct | ValueType::Null) | None => {
+            unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() })
+        }
+    };
+    let literal = Literal::new(span, value_literal);
+    if options.boolean_keywords || options.language_version < Version::V3_1 {
+        literal
+    } else {
+        visit_boolean_as_string(literal)
+    }
+}
+
+pub(super) fn visit_range_literal(node: Node<'_>, options: &ParserOptions) -> Result<Literal> {
+    debug_assert_eq!(node.as_rule(), Rule::range_literal);
+    let span = node.span();
+    let mut children = node.into_children();
+    let start = visit_value_literal(children.consume_expected(Rule::value_literal), options);
+    let inclusive_end = children.try_consume_expected(Rule::range_exclusive_end).is_none();
+    let end = visit_value_literal(children.consume_expected(Rule::value_literal), options);
+    debug_assert_eq!(children.try_consume_any(), None);
+    if start.value_type() != end.value_type() {
+        let (start, end) = (start.value_type().to_string(), end.value_type().to_string());
+        Err(TypeQLError::MixedRangeTypes { start, end, span })?
+    }
+    let range = RangeLiteral { start: Box::new(start), end: Box::new(end), inclusive_end };
+    Ok(Literal::new(span, ValueLiteral::Range(range)))
+}
+
+pub(super) fn visit_value_literal_lenient(node: Node<'_>, options: &ParserOptions) -> Result<Option<Literal>> {
+    debug_assert_eq!(node.as_rule(), Rule::value_literal_lenient);
+    let span = node.span();
+    let original = node.as_str().to_owned();
+    let child = node.into_child();
+    let value_literal = match child.as_rule() {
+        Rule::currency_decimal | Rule::percent_decimal if !options.allow_currency_and_percent => return Ok(None),
+        Rule::suffixed_integer if !options.allow_unit_suffixes => return Ok(None),
+        Rule::parenthesized_decimal if !options.allow_parenthesized_negatives => return Ok(None),
+        Rule::week_date if !options.allow_week_dates => return Ok(None),
+        Rule::ordinal_date if !options.allow_ordinal_dates => return Ok(None),
+        Rule::radix_integer if !options.allow_radix_integers => return Ok(None),
+        Rule::cast_literal if !options.allow_casts => return Ok(None),
+        Rule::epoch_seconds if !options.allow_epoch_seconds => return Ok(None),
+        Rule::point_decimal if !options.allow_bare_decimal_points => return Ok(None),
+        Rule::currency_decimal => ValueLiteral::Decimal(visit_currency_decimal(child)),
+        Rule::percent_decimal => ValueLiteral::Decimal(visit_percent_decimal(child)),
+        Rule::suffixed_integer => ValueLiteral::Integer(visit_suffixed_integer(child)?),
+        Rule::parenthesized_decimal => ValueLiteral::Decimal(visit_parenthesized_decimal(child)),
+        Rule::week_date => ValueLiteral::Date(visit_week_date(child)?),
+        Rule::ordinal_date => ValueLiteral::Date(visit_ordinal_date(child)?),
+        Rule::radix_integer => ValueLiteral::Integer(visit_radix_integer(child)?),
+        Rule::cast_literal => visit_cast_literal(child)?,
+        Rule::epoch_seconds => ValueLiteral::DateTimeTz(visit_epoch_seconds(child)?),
+        Rule::point_decimal => ValueLiteral::Decimal(visit_point_decimal(child)),
+        Rule::slash_date => match options.slash_date_order {
+            Some(order) => ValueLiteral::Date(visit_slash_date(child, order)?),
+            None => return Ok(None),
+        },
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     };
-    Literal::new(span, value_literal)
+    Ok(Some(Literal::new(span, value_literal).with_original(original)))
+}
+
+/// Null tokens are configured in the parser options, so are matched against the whole input rather than by the grammar.
+pub(super) fn visit_null_literal(literal: &str) -> Literal {
+    let span = pest::Span::new(literal, 0, literal.len()).and_then(|span| span.span());
+    Literal::new(span, ValueLiteral::Null(NullLiteral { value: literal.to_owned() }))
+}
+
+/// Whitespace around the `-` and `:` separators of dates and times is removed before parsing, rather than allowed by
+/// the grammar, so that the space before an IANA time zone still separates it from the time. Only dates and times are
+/// accepted this way.
+pub(super) fn visit_spaced_date_time(literal: &str, options: &ParserOptions) -> Option<Result<Literal>> {
+    let unspaced = remove_separator_whitespace(literal);
+    let node = match try_parse_single_with_options(Rule::eof_value_literal, &unspaced, options)? {
+        Ok(node) => node,
+        Err(error) => return Some(Err(error)),
+    };
+    let parsed = visit_value_literal(node.into_children().consume_expected(Rule::value_literal), options);
+    if !matches!(parsed.value_type(), ValueType::Date | ValueType::DateTime | ValueType::DateTimeTz) {
+        return None;
+    }
+    let span = pest::Span::new(literal, 0, literal.len()).and_then(|span| span.span());
+    Some(Ok(parsed.with_span(span).with_original(literal.to_owned())))
+}
+
+fn remove_separator_whitespace(literal: &str) -> String {
+    let mut unspaced = String::with_capacity(literal.len());
+    let mut rest = literal;
+    while let Some(char) = rest.chars().next() {
+        let trimmed = rest.trim_start();
+        if trimmed.len() == rest.len() {
+            unspaced.push(char);
+            rest = &rest[char.len_utf8()..];
+            continue;
+        }
+        if !trimmed.starts_with(['-', ':']) && !unspaced.ends_with(['-', ':']) {
+            unspaced.push_str(&rest[..rest.len() - trimmed.len()]);
+        }
+        rest = trimmed;
+    }
+    unspaced
+}
+
+/// Relative keywords are resolved against the `now` given in the parser options, so are matched against the whole input
+/// rather than by the grammar.
+pub(super) fn visit_relative_keyword(literal: &str, now: NaiveDateTime) -> Option<Literal> {
+    let value_literal = match literal {
+        "now" => ValueLiteral::DateTime(now.into()),
+        "today" => ValueLiteral::Date(DateLiteral { date: now.date().into() }),
+        "yesterday" => ValueLiteral::Date(DateLiteral { date: now.date().pred_opt()?.into() }),
+        "tomorrow" => ValueLiteral::Date(DateLiteral { date: now.date().succ_opt()?.into() }),
+        _ => return None,
+    };
+    let span = pest::Span::new(literal, 0, literal.len()).and_then(|span| span.span());
+    Some(Literal::new(span, value_literal).with_original(literal.to_owned()))
+}
+
+/// A boolean literal as the string of its keyword, for parser options without a boolean type. Other literals are
+/// returned unchanged.
+fn visit_boolean_as_string(literal: Literal) -> Literal {
+    let ValueLiteral::Boolean(BooleanLiteral { value }) = &literal.inner else { return literal };
+    let keyword = value.clone();
+    let string = StringLiteral { value: format!("\"{keyword}\"") };
+    literal.map_value(|_| ValueLiteral::String(string)).with_original(keyword)
+}
+
+fn visit_boolean_literal(node: Node<'_>) -> BooleanLiteral {
+    debug_assert_eq!(node.as_rule(), Rule::boolean_literal);
+    BooleanLiteral { value: node.as_str().to_owned() }
 }
 
 fn visit_sign(node: Node<'_>) -> Sign {
@@ -54,11 +210,24 @@ pub(super) fn visit_numeric_literal(node: Node<'_>) -> NumericLiteral {
     NumericLiteral { value: node.as_str().to_owned() }
 }
 
+fn visit_string_literal(node: Node<'_>) -> StringLiteral {
+    match node.as_rule() {
+        Rule::quoted_string_literal => visit_quoted_string_literal(node),
+        Rule::block_string_literal => visit_block_string_literal(node),
+        _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: node.to_string() }),
+    }
+}
+
 pub(super) fn visit_quoted_string_literal(node: Node<'_>) -> StringLiteral {
     debug_assert_eq!(node.as_rule(), Rule::quoted_string_literal);
     StringLiteral { value: node.as_str().to_owned() }
 }
 
+fn visit_block_string_literal(node: Node<'_>) -> StringLiteral {
+    debug_assert_eq!(node.as_rule(), Rule::block_string_literal);
+    StringLiteral { value: node.as_str().to_owned() }
+}
+
 fn visit_signed_integer(node: Node<'_>) -> SignedIntegerLiteral {
     debug_assert_eq!(node.as_rule(), Rule::signed_integer);
     let mut children = node.into_children();
@@ -90,21 +259,190 @@ fn visit_signed_decimal(node: Node<'_>) -> SignedDecimalLiteral {
     SignedDecimalLiteral { sign, decimal }
 }
 
+fn visit_currency_decimal(node: Node<'_>) -> SignedDecimalLiteral {
+    debug_assert_eq!(node.as_rule(), Rule::currency_decimal);
+    let mut children = node.into_children();
+    let sign = children.try_consume_expected(Rule::sign).map(visit_sign);
+    children.skip_expected(Rule::currency_symbol);
+    let amount = children.consume_expected(Rule::currency_amount).as_str().replace(',', "");
+    debug_assert_eq!(children.try_consume_any(), None);
+    let decimal = if amount.contains('.') { amount } else { amount + ".0" };
+    SignedDecimalLiteral { sign, decimal }
+}
+
+fn visit_percent_decimal(node: Node<'_>) -> SignedDecimalLiteral {
+    debug_assert_eq!(node.as_rule(), Rule::percent_decimal);
+    let mut children = node.into_children();
+    let sign = children.try_consume_expected(Rule::sign).map(visit_sign);
+    let amount = children.consume_expected(Rule::percent_amount).as_str();
+    debug_assert_eq!(children.try_consume_any(), None);
+    SignedDecimalLiteral { sign, decimal: divide_by_hundred(amount) }
+}
+
+fn visit_parenthesized_decimal(node: Node<'_>) -> SignedDecimalLiteral {
+    debug_assert_eq!(node.as_rule(), Rule::parenthesized_decimal);
+    let decimal = node.into_child().as_str().to_owned();
+    SignedDecimalLiteral { sign: Some(Sign::Minus), decimal }
+}
+
+fn visit_point_decimal(node: Node<'_>) -> SignedDecimalLiteral {
+    debug_assert_eq!(node.as_rule(), Rule::point_decimal);
+    let mut children = node.into_children();
+    let sign = children.try_consume_expected(Rule::sign).map(visit_sign);
+    let digits = children.consume_expected(Rule::point_decimal_digits).as_str();
+    debug_assert_eq!(children.try_consume_any(), None);
+    let decimal = match digits.strip_suffix('.') {
+        Some(integral) => format!("{integral}.0"),
+        None => format!("0{digits}"),
+    };
+    SignedDecimalLiteral { sign, decimal }
+}
+
+fn visit_week_date(node: Node<'_>) -> Result<DateLiteral> {
+    debug_assert_eq!(node.as_rule(), Rule::week_date);
+    let literal = node.as_str().to_owned();
+    let mut children = node.into_children();
+    let mut number = |rule| children.consume_expected(rule).as_str().parse().expect("week date parts are digits");
+    let (year, week, day) = (number(Rule::week_date_year), number(Rule::week_date_week), number(Rule::week_date_day));
+    debug_assert_eq!(children.try_consume_any(), None);
+    let date = DateFragment::from_iso_week_date(year as i32, week, day);
+    Ok(DateLiteral { date: date.ok_or(TypeQLError::InvalidWeekDate { literal })? })
+}
+
+fn visit_ordinal_date(node: Node<'_>) -> Result<DateLiteral> {
+    debug_assert_eq!(node.as_rule(), Rule::ordinal_date);
+    let literal = node.as_str().to_owned();
+    let mut children = node.into_children();
+    let mut number = |rule| children.consume_expected(rule).as_str().parse().expect("ordinal date parts are digits");
+    let (year, day) = (number(Rule::ordinal_date_year), number(Rule::ordinal_date_day));
+    debug_assert_eq!(children.try_consume_any(), None);
+    let date = DateFragment::from_ordinal_date(year as i32, day);
+    Ok(DateLiteral { date: date.ok_or(TypeQLError::InvalidOrdinalDate { literal })? })
+}
+
+fn visit_epoch_seconds(node: Node<'_>) -> Result<DateTimeTZLiteral> {
+    debug_assert_eq!(node.as_rule(), Rule::epoch_seconds);
+    let seconds = node.into_child().as_str();
+    let out_of_range = || TypeQLError::EpochOutOfRange { seconds: seconds.to_owned() };
+    let date_time = seconds.parse().ok().and_then(DateTimeTZLiteral::from_unix_timestamp);
+    Ok(date_time.ok_or_else(out_of_range)?)
+}
+
+fn visit_slash_date(node: Node<'_>, order: DateOrder) -> Result<DateLiteral> {
+    debug_assert_eq!(node.as_rule(), Rule::slash_date);
+    let literal = node.as_str().to_owned();
+    let mut children = node.into_children();
+    let [first, second, third] = [(); 3].map(|()| children.consume_expected(Rule::slash_date_part).as_str());
+    debug_assert_eq!(children.try_consume_any(), None);
+    let (year, month, day) = match order {
+        DateOrder::YMD => (first, second, third),
+        DateOrder::MDY => (third, first, second),
+        DateOrder::DMY => (third, second, first),
+    };
+    let invalid = || TypeQLError::InvalidDate { date: literal.clone() };
+    if year.len() != 4 || month.len() > 2 || day.len() > 2 {
+        Err(invalid())?
+    }
+    let number = |part: &str| part.parse().expect("slash date parts are digits");
+    let date = NaiveDate::from_ymd_opt(number(year) as i32, number(month), number(day)).ok_or_else(invalid)?;
+    Ok(DateLiteral { date: date.into() })
+}
+
+/// The sign applies to the decoded magnitude, so `-0xFF` is `-255`; the grammar has no sign after the prefix.
+fn visit_radix_integer(node: Node<'_>) -> Result<SignedIntegerLiteral> {
+    debug_assert_eq!(node.as_rule(), Rule::radix_integer);
+    let literal = node.as_str().to_owned();
+    let mut children = node.into_children();
+    let sign = children.try_consume_expected(Rule::sign).map(visit_sign);
+    let digits = children.consume_any();
+    let radix = match digits.as_rule() {
+        Rule::hexadecimal_integer => 16,
+        Rule::binary_integer => 2,
+        _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: digits.to_string() }),
+    };
+    debug_assert_eq!(children.try_consume_any(), None);
+    let magnitude = u128::from_str_radix(&digits.as_str()[2..], radix)
+        .map_err(|source| TypeQLError::IntegerOutOfRange { integer: literal, source: Some(source) })?;
+    Ok(SignedIntegerLiteral { sign, integral: magnitude.to_string() })
+}
+
+/// The string's content is parsed as a literal of the annotated type, so `"5"::integer` is the integer `5`.
+fn visit_cast_literal(node: Node<'_>) -> Result<ValueLiteral> {
+    debug_assert_eq!(node.as_rule(), Rule::cast_literal);
+    let span = node.span();
+    let mut children = node.into_children();
+    let string = visit_quoted_string_literal(children.consume_expected(Rule::quoted_string_literal));
+    let value_type = children.consume_expected(Rule::cast_value_type).as_str();
+    debug_assert_eq!(children.try_consume_any(), None);
+    if value_type == ValueType::String.as_str() {
+        return Ok(ValueLiteral::String(string));
+    }
+    let illegal =
+        || TypeQLError::IllegalCoercion { literal: string.to_string(), value_type: value_type.to_owned(), span };
+    let content = string.unescape()?;
+    let node = parse_exact(Rule::value_literal, &content, value_type).map_err(|_| illegal())?;
+    match visit_value_literal(node, &ParserOptions::default()).inner {
+        value if value.value_type().as_str() == value_type => Ok(value),
+        _ => Err(illegal().into()),
+    }
+}
+
+fn visit_suffixed_integer(node: Node<'_>) -> Result<SignedIntegerLiteral> {
+    debug_assert_eq!(node.as_rule(), Rule::suffixed_integer);
+    let literal = node.as_str().to_owned();
+    let mut children = node.into_children();
+    let sign = children.try_consume_expected(Rule::sign).map(visit_sign);
+    let integral = visit_integer_literal(children.consume_expected(Rule::integer_literal)).value;
+    let suffix = children.consume_expected(Rule::unit_suffix).as_str();
+    debug_assert_eq!(children.try_consume_any(), None);
+    let zeros = match suffix {
+        "k" => 3,
+        "M" => 6,
+        "G" => 9,
+        "T" => 12,
+        _ => return Err(TypeQLError::UnknownUnitSuffix { literal, suffix: suffix.to_owned() }.into()),
+    };
+    let integral = match integral.trim_start_matches('0') {
+        "" => "0".to_owned(),
+        digits => digits.to_owned() + &"0".repeat(zeros),
+    };
+    Ok(SignedIntegerLiteral { sign, integral })
+}
+
+fn divide_by_hundred(amount: &str) -> String {
+    let (integral, fractional) = amount.split_once('.').unwrap_or((amount, ""));
+    let digits = format!("{integral}{fractional}");
+    let point = integral.len() as isize - 2;
+    let (integral, fractional) = if point > 0 {
+        let (integral, fractional) = digits.split_at(point as usize);
+        (integral.trim_start_matches('0').to_owned(), fractional.to_owned())
+    } else {
+        (String::new(), "0".repeat(point.unsigned_abs()) + &digits)
+    };
+    let integral = if integral.is_empty() { "0" } else { &integral };
+    let fractional = if fractional.is_empty() { "0" } else { &fractional };
+    format!("{integral}.{fractional}")
+}
+
 fn visit_datetime_tz_literal(node: Node<'_>) -> DateTimeTZLiteral {
     debug_assert_eq!(node.as_rule(), Rule::datetime_tz_literal);
     let mut children = node.into_children();
     let date = visit_date_fragment(children.consume_expected(Rule::date_fragment));
     let time = visit_time(children.consume_expected(Rule::time));
-    let tz_node = children.consume_any();
-    let timezone = match tz_node.as_rule() {
-        Rule::iana_timezone => TimeZone::IANA(tz_node.as_str().to_owned()),
-        Rule::iso8601_timezone_offset => TimeZone::ISO(tz_node.as_str().to_owned()),
-        _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: tz_node.to_string() }),
-    };
+    let timezone = visit_timezone(children.consume_any());
     debug_assert_eq!(children.try_consume_any(), None);
     DateTimeTZLiteral { date, time, timezone }
 }
 
+fn visit_timezone(node: Node<'_>) -> TimeZone {
+    match node.as_rule() {
+        Rule::iana_timezone | Rule::standalone_iana_timezone => TimeZone::IANA(node.as_str().to_owned()),
+        Rule::iso8601_timezone_offset | Rule::standalone_timezone_offset if node.as_str() == "Z" => TimeZone::Utc,
+        Rule::iso8601_timezone_offset | Rule::standalone_timezone_offset => TimeZone::ISO(node.as_str().to_owned()),
+        _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: node.to_string() }),
+    }
+}
+
 fn visit_datetime_literal(node: Node<'_>) -> DateTimeLiteral {
     debug_assert_eq!(node.as_rule(), Rule::datetime_literal);
     let mut children = node.into_children();
@@ -120,16 +458,82 @@ fn visit_date_literal(node: Node<'_>) -> DateLiteral {
     DateLiteral { date }
 }
 
+/// Datetimes with several time zones are only parsed to report them, so the zones are not visited.
+pub(super) fn visit_duplicate_timezone(node: Node<'_>) -> TypeQLError {
+    debug_assert_eq!(node.as_rule(), Rule::duplicate_timezone);
+    let literal = node.as_str().to_owned();
+    let mut children = node.into_children();
+    children.skip_expected(Rule::date_fragment);
+    children.skip_expected(Rule::time);
+    children.consume_any(); // the first time zone is accepted, the second is the error
+    let span = children.consume_any().span();
+    TypeQLError::DuplicateTimeZone { literal, span }
+}
+
+/// The error for a datetime whose time zone has the wrong delimiter, spanning the time zone and its delimiter.
+pub(super) fn visit_misdelimited_timezone(node: Node<'_>) -> TypeQLError {
+    debug_assert_eq!(node.as_rule(), Rule::misdelimited_timezone);
+    let literal = node.as_str().to_owned();
+    let mut children = node.into_children();
+    children.skip_expected(Rule::date_fragment);
+    children.skip_expected(Rule::time);
+    let zone = children.consume_expected(Rule::misdelimited_timezone_zone);
+    TypeQLError::MisdelimitedTimeZone { literal, zone: zone.as_str().to_owned(), span: zone.span() }
+}
+
+/// The error for a number that separates its digits with both `_` and `,`, spanning the first separator that
+/// conflicts with an earlier one, or `None` if the separators are consistent.
+pub(super) fn visit_mixed_separators(node: Node<'_>) -> Option<TypeQLError> {
+    debug_assert_eq!(node.as_rule(), Rule::mixed_separators);
+    let literal = node.as_str().to_owned();
+    let mut grouping = None;
+    for separator in node.into_children().filter(|child| child.as_rule() == Rule::number_separator) {
+        match (grouping, separator.as_str()) {
+            (_, ".") => (),
+            (None, kind) => grouping = Some(kind),
+            (Some(first), kind) if first != kind => {
+                return Some(TypeQLError::InconsistentSeparators { literal, span: separator.span() })
+            }
+            (Some(_), _) => (),
+        }
+    }
+    None
+}
+
 fn visit_date_fragment(node: Node<'_>) -> DateFragment {
     debug_assert_eq!(node.as_rule(), Rule::date_fragment);
     let mut children = node.into_children();
     let year = children.consume_expected(Rule::year).as_str().to_owned();
-    let month = children.consume_expected(Rule::month).as_str().to_owned();
-    let day = children.consume_expected(Rule::day).as_str().to_owned();
+    let month = Some(children.consume_expected(Rule::month).as_str().to_owned());
+    let day = Some(children.consume_expected(Rule::day).as_str().to_owned());
     debug_assert_eq!(children.try_consume_any(), None);
     DateFragment { year, month, day }
 }
 
+/// Partial dates are only accepted when enabled in the parser options, which is checked by the caller.
+pub(super) fn visit_partial_date(node: Node<'_>) -> Literal {
+    debug_assert_eq!(node.as_rule(), Rule::partial_date);
+    let span = node.span();
+    let mut children = node.into_children();
+    let year = children.consume_expected(Rule::partial_date_year).as_str().to_owned();
+    let month = children.try_consume_expected(Rule::month).map(|month| month.as_str().to_owned());
+    debug_assert_eq!(children.try_consume_any(), None);
+    Literal::new(span, ValueLiteral::Date(DateLiteral { date: DateFragment { year, month, day: None } }))
+}
+
+pub(super) fn visit_basic_date(node: Node<'_>) -> Literal {
+    debug_assert_eq!(node.as_rule(), Rule::basic_date);
+    let span = node.span();
+    let original = node.as_str().to_owned();
+    let mut children = node.into_children();
+    let year = children.consume_expected(Rule::basic_date_year).as_str().to_owned();
+    let month = Some(children.consume_expected(Rule::month).as_str().to_owned());
+    let day = Some(children.consume_expected(Rule::day).as_str().to_owned());
+    debug_assert_eq!(children.try_consume_any(), None);
+    Literal::new(span, ValueLiteral::Date(DateLiteral { date: DateFragment { year, month, day } }))
+        .with_original(original)
+}
+
 fn visit_time(node: Node<'_>) -> TimeFragment {
     debug_assert_eq!(node.as_rule(), Rule::time);
     let mut children = node.into_children();
@@ -212,3 +616,65 @@ fn visit_duration_seconds(node: Node<'_>) -> NumericLiteral {
     debug_assert_eq!(node.as_rule(), Rule::duration_seconds);
     visit_numeric_literal(node.into_child())
 }
+
+/// Parses the whole of `literal` as `rule`, for parsing a literal whose kind is known in advance.
+fn parse_exact<'a>(rule: Rule, literal: &'a str, kind: &str) -> Result<Node<'a>> {
+    TypeQLParser::parse(rule, literal)
+        .ok()
+        .map(|mut nodes| nodes.consume_any())
+        .filter(|node| node.as_str() == literal)
+        .ok_or_else(|| TypeQLError::UnexpectedLiteralKind { literal: literal.to_owned(), kind: kind.to_owned() }.into())
+}
+
+impl StringLiteral {
+    /// Whether the string's content is a literal of the given type, e.g. for a lint suggesting a date rather than the
+    /// string `"2023-01-01"`. The content is only matched against the grammar, not visited. Strings, structs and nulls
+    /// have no other syntax, so a string never could be one.
+    pub fn could_be(&self, value_type: ValueType) -> bool {
+        let rule = match value_type {
+            ValueType::Boolean => Rule::boolean_literal,
+            ValueType::Integer => Rule::signed_integer,
+            ValueType::Decimal => Rule::signed_decimal,
+            ValueType::Date => Rule::date_literal,
+            ValueType::DateTime => Rule::datetime_literal,
+            ValueType::DateTimeTz => Rule::datetime_tz_literal,
+            ValueType::Duration => Rule::duration_literal,
+            ValueType::TimeZone => Rule::timezone_literal,
+            ValueType::String | ValueType::Range | ValueType::Struct | ValueType::Null => return false,
+        };
+        self.unescape().is_ok_and(|content| parse_exact(rule, &content, value_type.as_str()).is_ok())
+    }
+}
+
+macro_rules! impl_from_str {
+    ($($type:ty => $rule:ident, $visit:ident, $kind:literal;)*) => {$(
+        impl TryFrom<&str> for $type {
+            type Error = Error;
+
+            fn try_from(literal: &str) -> Result<Self> {
+                Ok($visit(parse_exact(Rule::$rule, literal, $kind)?))
+            }
+        }
+
+        impl FromStr for $type {
+            type Err = Error;
+
+            fn from_str(literal: &str) -> Result<Self> {
+                Self::try_from(literal)
+            }
+        }
+    )*};
+}
+
+impl_from_str! {
+    BooleanLiteral => boolean_literal, visit_boolean_literal, "boolean";
+    IntegerLiteral => integer_literal, visit_integer_literal, "unsigned integer";
+    SignedIntegerLiteral => signed_integer, visit_signed_integer, "integer";
+    SignedDecimalLiteral => signed_decimal, visit_signed_decimal, "decimal";
+    DateFragment => date_fragment, visit_date_fragment, "date";
+    TimeFragment => time, visit_time, "time";
+    DateLiteral => date_literal, visit_date_literal, "date";
+    DateTimeLiteral => datetime_literal, visit_datetime_literal, "datetime";
+    DateTimeTZLiteral => datetime_tz_literal, visit_datetime_tz_literal, "datetime-tz";
+    DurationLiteral => duration_literal, visit_duration_literal, "duration";
+}
@@ -4,17 +4,31 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use pest::Parser;
+use std::iter;
+
+use pest::{
+    error::{Error as PestError, ErrorVariant},
+    Parser,
+};
 use pest_derive::Parser;
 
 use self::{
     define::{function::visit_definition_function, struct_::visit_definition_struct, visit_query_define},
+    literal::{
+        visit_basic_date, visit_duplicate_timezone, visit_misdelimited_timezone, visit_mixed_separators,
+        visit_null_literal, visit_partial_date, visit_range_literal, visit_relative_keyword, visit_spaced_date_time,
+        visit_value_literal, visit_value_literal_lenient,
+    },
     type_::visit_label,
     undefine::visit_query_undefine,
 };
+pub use self::{
+    literal::rule_to_value_type,
+    options::{DateOrder, ParserOptions, Version, GRAMMAR_VERSION},
+};
 use crate::{
     common::{
-        error::{syntax_error, TypeQLError},
+        error::{syntax_error, Error, TypeQLError},
         identifier::Identifier,
         token, LineColumn, Span, Spanned,
     },
@@ -22,6 +36,7 @@ use crate::{
     query::{Query, SchemaQuery},
     schema::definable,
     type_::Label,
+    value::Literal,
     variable::{Optional, Variable},
     Result,
 };
@@ -30,6 +45,7 @@ mod annotation;
 mod define;
 mod expression;
 mod literal;
+mod options;
 mod pipeline;
 mod redefine;
 mod statement;
@@ -48,8 +64,14 @@ type ChildNodes<'a> = pest::iterators::Pairs<'a, Rule>;
 
 impl Spanned for Node<'_> {
     fn span(&self) -> Option<Span> {
-        let (begin_line, begin_col) = self.as_span().start_pos().line_col();
-        let (end_line, end_col) = self.as_span().end_pos().line_col();
+        self.as_span().span()
+    }
+}
+
+impl Spanned for pest::Span<'_> {
+    fn span(&self) -> Option<Span> {
+        let (begin_line, begin_col) = self.start_pos().line_col();
+        let (end_line, end_col) = self.end_pos().line_col();
         Some(Span {
             begin: LineColumn { line: begin_line as u32, column: begin_col as u32 },
             end: LineColumn { line: end_line as u32, column: end_col as u32 },
@@ -132,13 +154,97 @@ fn parse_single(rule: Rule, string: &str) -> Result<Node<'_>> {
     Ok(parse(rule, string)?.consume_any())
 }
 
+fn parse_single_with_options<'a>(rule: Rule, string: &'a str, options: &ParserOptions) -> Result<Node<'a>> {
+    let node = parse_single(rule, string)?;
+    check_version(string, &node, options)?;
+    check_limits(&node, options)?;
+    Ok(node)
+}
+
+/// Parses as [`parse_single_with_options`] does, but without the debug output of [`parse`], for a first attempt whose
+/// syntax error may be replaced by a later form accepting the input.
+fn parse_single_quietly_with_options<'a>(rule: Rule, string: &'a str, options: &ParserOptions) -> Result<Node<'a>> {
+    let node = TypeQLParser::parse(rule, string).map_err(|error| syntax_error(string, error))?.consume_any();
+    check_version(string, &node, options)?;
+    check_limits(&node, options)?;
+    Ok(node)
+}
+
+/// Parses as [`parse_single_with_options`] does, or `None` if the input is not a `rule`, for the forms of a literal
+/// that are tried in turn.
+fn try_parse_single_with_options<'a>(rule: Rule, string: &'a str, options: &ParserOptions) -> Option<Result<Node<'a>>> {
+    let node = TypeQLParser::parse(rule, string).ok()?.consume_any();
+    let checked = check_version(string, &node, options).and_then(|()| check_limits(&node, options));
+    Some(checked.map(|()| node).map_err(Into::into))
+}
+
+/// Rejects syntax introduced after the `language_version` of `options` as a syntax error at the first use, as if the
+/// grammar of that version were parsed.
+fn check_version(string: &str, node: &Node<'_>, options: &ParserOptions) -> std::result::Result<(), TypeQLError> {
+    if options.language_version >= Version::V3_1 {
+        return Ok(());
+    }
+    let newer = node.clone().into_inner().flatten().find_map(|node| match node.as_rule() {
+        Rule::block_string_literal => Some((node, "block strings")),
+        Rule::timezone_literal => Some((node, "standalone time zones")),
+        _ => None,
+    });
+    match newer {
+        Some((node, syntax)) => {
+            let message = format!("{syntax} require TypeQL {}", Version::V3_1);
+            let error = PestError::<Rule>::new_from_span(ErrorVariant::CustomError { message }, node.as_span());
+            Err(syntax_error(string, error))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Checks the syntax tree of parsed input against the limits of `options`, before it is visited. Every entry point
+/// that takes options calls this on each tree it parses.
+/// Rejects an input longer than the `max_input_len` of `options`, before any of it is parsed.
+fn check_input_len(input: &str, options: &ParserOptions) -> std::result::Result<(), TypeQLError> {
+    match options.max_input_len {
+        Some(max) if input.len() > max => Err(TypeQLError::InputTooLong { len: input.len(), max }),
+        _ => Ok(()),
+    }
+}
+
+fn check_limits(node: &Node<'_>, options: &ParserOptions) -> std::result::Result<(), TypeQLError> {
+    if let Some(limit) = options.max_nodes {
+        // counts the root too, and stops counting as soon as the limit is exceeded
+        if iter::once(node.clone()).chain(node.clone().into_inner().flatten()).nth(limit).is_some() {
+            Err(TypeQLError::InputTooComplex { limit })?;
+        }
+    }
+    if let Some(max) = options.max_string_len {
+        // checked on the matched text, before any string is unescaped into a new allocation
+        let mut strings = node
+            .clone()
+            .into_inner()
+            .flatten()
+            .filter(|node| matches!(node.as_rule(), Rule::quoted_string_literal | Rule::block_string_literal));
+        if let Some(string) = strings.find(|string| string.as_str().len() > max) {
+            Err(TypeQLError::StringLiteralTooLong { len: string.as_str().len(), max, span: string.span() })?;
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn visit_eof_query(query: &str) -> Result<Query> {
-    Ok(visit_query(parse_single(Rule::eof_query, query)?.into_children().consume_expected(Rule::query)))
+    let node = parse_single(Rule::eof_query, query)?;
+    Ok(visit_query(node.into_children().consume_expected(Rule::query), &ParserOptions::default()))
+}
+
+pub(crate) fn visit_eof_query_with_options(query: &str, options: &ParserOptions) -> Result<Query> {
+    check_input_len(query, options)?;
+    let node = parse_single_with_options(Rule::eof_query, query, options)?;
+    Ok(visit_query(node.into_children().consume_expected(Rule::query), options))
 }
 
 pub(crate) fn visit_eof_definition_function(query: &str) -> Result<definable::Function> {
     Ok(visit_definition_function(
         parse_single(Rule::eof_definition_function, query)?.into_children().consume_expected(Rule::definition_function),
+        &ParserOptions::default(),
     ))
 }
 
@@ -148,6 +254,220 @@ pub(crate) fn visit_eof_definition_struct(query: &str) -> Result<definable::Stru
     ))
 }
 
+pub(crate) fn normalize_literals(query: &str) -> Result<String> {
+    let node = parse_single(Rule::eof_query, query)?;
+    let mut normalized = String::with_capacity(query.len());
+    let mut copied = 0;
+    // literals never nest, so they are found in order and do not overlap
+    for literal in node.into_inner().flatten().filter(|node| node.as_rule() == Rule::query_value_literal) {
+        let (begin, end) = (literal.as_span().start(), literal.as_span().end());
+        normalized.push_str(&query[copied..begin]);
+        normalized.push_str(&visit_value_literal(literal, &ParserOptions::default()).to_canonical_string());
+        copied = end;
+    }
+    normalized.push_str(&query[copied..]);
+    Ok(normalized)
+}
+
+pub(crate) fn visit_eof_value_literal(literal: &str) -> Result<Literal> {
+    // tried without `parse`, whose debug output would report every range and clarified error as a failure
+    let options = &ParserOptions::default();
+    match TypeQLParser::parse(Rule::eof_value_literal, literal) {
+        Ok(mut nodes) => {
+            Ok(visit_value_literal(nodes.consume_any().into_children().consume_expected(Rule::value_literal), options))
+        }
+        Err(error) => visit_eof_range_literal(literal, options)
+            .unwrap_or_else(|| Err(clarify_literal_error(literal, syntax_error(literal, error).into()))),
+    }
+}
+
+pub(crate) fn visit_value_literal_partial<'a>(input: &'a str, options: &ParserOptions) -> Result<(Literal, &'a str)> {
+    // the literal's node would include leading whitespace in its span, so it is skipped first
+    check_input_len(input, options)?;
+    let trimmed = input.trim_start();
+    let node = parse_single_with_options(Rule::value_literal, trimmed, options)?;
+    let end = node.as_span().end();
+    let mut literal = visit_value_literal(node, options);
+    literal.reoffset(advance(LineColumn { line: 1, column: 1 }, &input[..input.len() - trimmed.len()]));
+    Ok((literal, &trimmed[end..]))
+}
+
+/// A range between two literals, tried once the input has failed to parse as a single literal. Ranges were introduced
+/// in [`Version::V3_1`].
+fn visit_eof_range_literal(literal: &str, options: &ParserOptions) -> Option<Result<Literal>> {
+    if options.language_version < Version::V3_1 {
+        return None;
+    }
+    let node = try_parse_single_with_options(Rule::eof_range_literal, literal, options)?;
+    Some(node.and_then(|node| visit_range_literal(node.into_children().consume_expected(Rule::range_literal), options)))
+}
+
+/// Replaces the syntax error of a literal with a clearer error for common mistakes the grammar rejects.
+fn clarify_literal_error(literal: &str, syntax_error: Error) -> Error {
+    if let Ok(mut nodes) = TypeQLParser::parse(Rule::eof_duplicate_timezone, literal) {
+        let node = nodes.consume_any().into_children().consume_expected(Rule::duplicate_timezone);
+        return visit_duplicate_timezone(node).into();
+    }
+    if let Ok(mut nodes) = TypeQLParser::parse(Rule::eof_misdelimited_timezone, literal) {
+        let node = nodes.consume_any().into_children().consume_expected(Rule::misdelimited_timezone);
+        return visit_misdelimited_timezone(node).into();
+    }
+    if let Ok(mut nodes) = TypeQLParser::parse(Rule::eof_mixed_separators, literal) {
+        let node = nodes.consume_any().into_children().consume_expected(Rule::mixed_separators);
+        if let Some(error) = visit_mixed_separators(node) {
+            return error.into();
+        }
+    }
+    if let Ok(mut nodes) = TypeQLParser::parse(Rule::eof_empty_number, literal) {
+        let span = nodes.consume_any().into_children().consume_expected(Rule::sign).span();
+        return TypeQLError::EmptyNumericLiteral { span }.into();
+    }
+    if let Ok(mut nodes) = TypeQLParser::parse(Rule::eof_non_finite_decimal, literal) {
+        let node = nodes.consume_any().into_children().consume_expected(Rule::non_finite_decimal);
+        return TypeQLError::NonFiniteDecimal { literal: literal.to_owned(), span: node.span() }.into();
+    }
+    syntax_error
+}
+
+pub(crate) fn visit_eof_value_literal_with_options(literal: &str, options: &ParserOptions) -> Result<Literal> {
+    check_input_len(literal, options)?;
+    let lenient = options.language_version >= Version::V3_1;
+    // a bare year or basic date is also a valid integer, so these dates take precedence over the strict grammar
+    if lenient && options.allow_partial_dates {
+        if let Some(node) = try_parse_single_with_options(Rule::eof_partial_date, literal, options) {
+            return Ok(visit_partial_date(node?.into_children().consume_expected(Rule::partial_date)));
+        }
+    }
+    if lenient && options.allow_basic_dates {
+        if let Some(node) = try_parse_single_with_options(Rule::eof_basic_date, literal, options) {
+            return Ok(visit_basic_date(node?.into_children().consume_expected(Rule::basic_date)));
+        }
+    }
+    let strict_error = match parse_single_quietly_with_options(Rule::eof_value_literal, literal, options) {
+        Ok(node) => {
+            let node = node.into_children().consume_expected(Rule::value_literal);
+            return Ok(visit_value_literal(node, options));
+        }
+        Err(error) => match visit_eof_range_literal(literal, options) {
+            Some(range) => return range,
+            None => clarify_literal_error(literal, error),
+        },
+    };
+    if !lenient {
+        return Err(strict_error);
+    }
+    if options.null_tokens.iter().any(|token| token == literal) {
+        return Ok(visit_null_literal(literal));
+    }
+    if let Some(now) = options.relative_datetime_now {
+        if let Some(literal) = visit_relative_keyword(literal, now) {
+            return Ok(literal);
+        }
+    }
+    if options.allow_spaced_date_separators {
+        if let Some(literal) = visit_spaced_date_time(literal, options) {
+            return literal;
+        }
+    }
+    // lenient forms that are disabled report the same error as any other invalid literal
+    let Some(node) = try_parse_single_with_options(Rule::eof_value_literal_lenient, literal, options) else {
+        return Err(strict_error);
+    };
+    let node = node?.into_children().consume_expected(Rule::value_literal_lenient);
+    visit_value_literal_lenient(node, options)?.ok_or(strict_error)
+}
+
+/// Parses whitespace-separated literals. A malformed literal is reported to `sink` and skipped up to the next
+/// whitespace, so that the literals after it are still parsed.
+pub(crate) fn visit_value_literals(
+    input: &str,
+    options: &ParserOptions,
+    mut sink: impl FnMut(TypeQLError),
+) -> Vec<Literal> {
+    let mut literals = Vec::new();
+    for literal in ValueLiterals::new(input, options.clone()) {
+        match literal {
+            Ok(literal) => literals.push(literal),
+            Err(error) => sink(error),
+        }
+    }
+    literals
+}
+
+/// Lazily parses whitespace-separated literals, spanned within the whole input. A malformed literal is an error
+/// spanning up to the next whitespace, after which parsing continues.
+pub(crate) struct ValueLiterals<'a> {
+    rest: &'a str,
+    position: LineColumn,
+    options: ParserOptions,
+    too_long: Option<TypeQLError>,
+}
+
+impl<'a> ValueLiterals<'a> {
+    /// An input over the `max_input_len` of `options` yields only the error, without parsing any literal.
+    pub(crate) fn new(input: &'a str, options: ParserOptions) -> Self {
+        let too_long = check_input_len(input, &options).err();
+        let rest = if too_long.is_some() { "" } else { input };
+        Self { rest, position: LineColumn { line: 1, column: 1 }, options, too_long }
+    }
+}
+
+impl Iterator for ValueLiterals<'_> {
+    type Item = std::result::Result<Literal, TypeQLError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(error) = self.too_long.take() {
+            return Some(Err(error));
+        }
+        let trimmed = self.rest.trim_start();
+        self.position = advance(self.position, &self.rest[..self.rest.len() - trimmed.len()]);
+        let rest = trimmed;
+        if rest.is_empty() {
+            self.rest = rest;
+            return None;
+        }
+        let position = self.position;
+        let node = TypeQLParser::parse(Rule::value_literal, rest)
+            .ok()
+            .map(|mut nodes| nodes.consume_any())
+            .filter(|node| rest[node.as_str().len()..].chars().next().map_or(true, char::is_whitespace))
+            .filter(|node| check_version(rest, node, &self.options).is_ok());
+        let (consumed, literal) = match node {
+            Some(node) => {
+                let consumed = node.as_str().len();
+                let literal = match check_limits(&node, &self.options) {
+                    Ok(()) => {
+                        let mut literal = visit_value_literal(node, &self.options);
+                        literal.reoffset(position);
+                        Ok(literal)
+                    }
+                    Err(TypeQLError::StringLiteralTooLong { len, max, span }) => {
+                        let span = span.and_then(|span| span.offset_to(position));
+                        Err(TypeQLError::StringLiteralTooLong { len, max, span })
+                    }
+                    Err(error) => Err(error),
+                };
+                (consumed, literal)
+            }
+            None => {
+                let token = &rest[..rest.find(char::is_whitespace).unwrap_or(rest.len())];
+                let span = Some(Span { begin: position, end: advance(position, token) });
+                (token.len(), Err(TypeQLError::InvalidValueLiteral { literal: token.to_owned(), span }))
+            }
+        };
+        self.position = advance(position, &rest[..consumed]);
+        self.rest = &rest[consumed..];
+        Some(literal)
+    }
+}
+
+fn advance(position: LineColumn, text: &str) -> LineColumn {
+    text.chars().fold(position, |LineColumn { line, column }, char| match char {
+        '\n' => LineColumn { line: line + 1, column: 1 },
+        _ => LineColumn { line, column: column + 1 },
+    })
+}
+
 pub(crate) fn visit_eof_label(label: &str) -> Result<Label> {
     let parsed = parse_single(Rule::eof_label, label)?.into_children().consume_expected(Rule::label);
     let string = parsed.as_str();
@@ -157,26 +477,26 @@ pub(crate) fn visit_eof_label(label: &str) -> Result<Label> {
     Ok(visit_label(parsed))
 }
 
-fn visit_query(node: Node<'_>) -> Query {
+fn visit_query(node: Node<'_>, options: &ParserOptions) -> Query {
     debug_assert_eq!(node.as_rule(), Rule::query);
     let mut children = node.into_children();
     let child = children.consume_any();
     let query = match child.as_rule() {
-        Rule::query_schema => Query::Schema(visit_query_schema(child)),
-        Rule::query_pipeline_preambled => Query::Pipeline(visit_query_pipeline_preambled(child)),
+        Rule::query_schema => Query::Schema(visit_query_schema(child, options)),
+        Rule::query_pipeline_preambled => Query::Pipeline(visit_query_pipeline_preambled(child, options)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     };
     debug_assert_eq!(children.try_consume_any(), None);
     query
 }
 
-fn visit_query_schema(node: Node<'_>) -> SchemaQuery {
+fn visit_query_schema(node: Node<'_>, options: &ParserOptions) -> SchemaQuery {
     debug_assert_eq!(node.as_rule(), Rule::query_schema);
     let mut children = node.into_children();
     let child = children.consume_any();
     let query = match child.as_rule() {
-        Rule::query_define => SchemaQuery::Define(visit_query_define(child)),
-        Rule::query_redefine => SchemaQuery::Redefine(visit_query_redefine(child)),
+        Rule::query_define => SchemaQuery::Define(visit_query_define(child, options)),
+        Rule::query_redefine => SchemaQuery::Redefine(visit_query_redefine(child, options)),
         Rule::query_undefine => SchemaQuery::Undefine(visit_query_undefine(child)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     };
@@ -0,0 +1,177 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use chrono::NaiveDateTime;
+
+/// The version of the grammar this crate parses by default, as recorded by tooling for the provenance of parsed
+/// literals. It is the text of [`Version::LATEST`].
+pub const GRAMMAR_VERSION: &str = "3.1";
+
+/// The order of the year, month and day in a slash-separated date, for
+/// [`ParserOptions::slash_date_order`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DateOrder {
+    /// `2023/02/01`
+    YMD,
+    /// `02/01/2023`, as in the United States
+    MDY,
+    /// `01/02/2023`, as in most of Europe
+    DMY,
+}
+
+/// A version of the TypeQL grammar, to parse input as written for an older release. Later versions compare greater.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl Version {
+    /// The strict syntax of [`crate::parse_query`] and [`crate::parse_value_literal`], with no lenient literal forms.
+    pub const V3_0: Version = Version { major: 3, minor: 0 };
+    /// Adds block strings (`"""text"""`), standalone time zones (`Europe/London`), value ranges (`1..5`), and the
+    /// lenient literal forms enabled by the other [`ParserOptions`], such as `allow_partial_dates`.
+    pub const V3_1: Version = Version { major: 3, minor: 1 };
+    pub const LATEST: Version = Self::V3_1;
+}
+
+impl Default for Version {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Options for [`crate::parse_query_with_options`], [`crate::parse_value_literal_with_options`] and the other
+/// `_with_options` entry points.
+/// The defaults accept exactly the syntax of [`crate::parse_query`] and [`crate::parse_value_literal`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParserOptions {
+    /// Accept currency-prefixed (`$1,234.50`) and percent-suffixed (`12%`) decimals as found in spreadsheet exports.
+    /// The parsed value is the plain decimal (`1234.50`, `0.12`), and the source text is kept as the literal's
+    /// [`original`](crate::value::Literal::original).
+    pub allow_currency_and_percent: bool,
+    /// Accept integers with an SI unit suffix (`5k`, `2M`, `3G`, `1T`) as entered by people, expanded to the plain
+    /// integer (`5000`) with the source text kept as the literal's [`original`](crate::value::Literal::original).
+    /// Other suffixes are rejected with [`UnknownUnitSuffix`](crate::common::error::TypeQLError::UnknownUnitSuffix).
+    pub allow_unit_suffixes: bool,
+    /// Accept decimals in parentheses (`(123.45)`), the accounting notation for negatives, as the negative decimal
+    /// (`-123.45`) with the source text kept as the literal's [`original`](crate::value::Literal::original).
+    pub allow_parenthesized_negatives: bool,
+    /// Accept decimals with no digits before or after the decimal point (`.5`, `5.`), as the decimal with a zero in
+    /// place of the missing digits (`0.5`, `5.0`) with the source text kept as the literal's
+    /// [`original`](crate::value::Literal::original). A decimal point alone is still rejected.
+    pub allow_bare_decimal_points: bool,
+    /// Sentinels for a missing value, such as `NULL`, `N/A` or `none` in imported data, parsed as
+    /// [`ValueLiteral::Null`](crate::value::ValueLiteral::Null). A token only matches the whole input exactly, and a
+    /// token that is already a valid literal, such as `false`, keeps its usual meaning. Empty by default.
+    pub null_tokens: Vec<String>,
+    /// Parse the keywords `true` and `false` as booleans, as in canonical TypeQL. On by default; turned off, the
+    /// keywords are parsed as the strings `"true"` and `"false"`, in queries and literals alike, for systems without a
+    /// boolean value type, with the keyword kept as the literal's [`original`](crate::value::Literal::original).
+    pub boolean_keywords: bool,
+    /// Accept month-granular (`2023-06`) and year-granular (`2023`) dates, parsed as a date literal whose omitted
+    /// components are `None`. A bare four-digit year is then read as a date rather than an integer.
+    pub allow_partial_dates: bool,
+    /// Accept ISO 8601 basic dates, with no separators (`20230101`), as the extended date (`2023-01-01`) with the
+    /// source text kept as the literal's [`original`](crate::value::Literal::original). Exactly eight digits are then
+    /// read as a date rather than an integer.
+    pub allow_basic_dates: bool,
+    /// Accept dates and times with whitespace around their `-` and `:` separators (`2023 - 01 - 01`), as found in
+    /// pasted text, parsed as the unspaced literal with the source text kept as the literal's
+    /// [`original`](crate::value::Literal::original).
+    pub allow_spaced_date_separators: bool,
+    /// Accept ISO 8601 week dates (`2023-W05-1`, the Monday of week 5), expanded to the calendar date with the source
+    /// text kept as the literal's [`original`](crate::value::Literal::original). A week the week year does not have,
+    /// or a weekday outside `1` to `7`, is rejected with
+    /// [`InvalidWeekDate`](crate::common::error::TypeQLError::InvalidWeekDate).
+    pub allow_week_dates: bool,
+    /// Accept ISO 8601 ordinal dates (`2023-032`, the 32nd day of 2023), expanded to the calendar date with the source
+    /// text kept as the literal's [`original`](crate::value::Literal::original). A day outside `1` to `365`, or `366`
+    /// in a leap year, is rejected with [`InvalidOrdinalDate`](crate::common::error::TypeQLError::InvalidOrdinalDate).
+    pub allow_ordinal_dates: bool,
+    /// Accept hexadecimal (`0xFF`) and binary (`0b1010`) integers, optionally signed (`-0xFF` is `-255`), decoded to a
+    /// decimal integer with the source text kept as the literal's [`original`](crate::value::Literal::original) and the
+    /// radix given by [`radix`](crate::value::Literal::radix). A magnitude beyond 128 bits is rejected with
+    /// [`IntegerOutOfRange`](crate::common::error::TypeQLError::IntegerOutOfRange).
+    pub allow_radix_integers: bool,
+    /// Accept strings cast to another value type, like `"5"::integer`, for values written as strings in untyped
+    /// contexts. The string's content is parsed as a literal of the type, with the cast kept as the literal's
+    /// [`original`](crate::value::Literal::original). Content that is not a literal of the type is rejected with
+    /// [`IllegalCoercion`](crate::common::error::TypeQLError::IllegalCoercion).
+    pub allow_casts: bool,
+    /// Accept dates separated by slashes (`01/02/2023`), as found in spreadsheet exports, reading their components in
+    /// this order, with the source text kept as the literal's [`original`](crate::value::Literal::original). The order
+    /// is never guessed, so `None`, the default, rejects slashes. The year must have four digits and the month and day
+    /// one or two, and a date that is not in the calendar is rejected with
+    /// [`InvalidDate`](crate::common::error::TypeQLError::InvalidDate).
+    pub slash_date_order: Option<DateOrder>,
+    /// Accept Unix timestamps in seconds, written `@1700000000` or `epoch(1700000000)`, as stored by some pipelines,
+    /// converted to the UTC datetime-tz with the timestamp kept as the literal's
+    /// [`original`](crate::value::Literal::original). A timestamp beyond the years a datetime supports is rejected with
+    /// [`EpochOutOfRange`](crate::common::error::TypeQLError::EpochOutOfRange).
+    pub allow_epoch_seconds: bool,
+    /// Accept the keywords `now`, `today`, `yesterday` and `tomorrow`, as typed in interactive tools, resolved against
+    /// this time to a datetime or date, with the keyword kept as the literal's
+    /// [`original`](crate::value::Literal::original). The time is given rather than read from the clock so that
+    /// parsing stays deterministic. `None`, the default, rejects the keywords.
+    pub relative_datetime_now: Option<NaiveDateTime>,
+    /// Reject inputs whose syntax tree has more than this many nodes, counting the root, with [`InputTooComplex`]. The
+    /// size is checked once the input is parsed and before the tree is visited, so this bounds the work of building
+    /// literals and queries from untrusted input, but not the work of parsing it: pair it with
+    /// [`max_input_len`](Self::max_input_len) for that. `None` places no limit.
+    ///
+    /// [`InputTooComplex`]: crate::common::error::TypeQLError::InputTooComplex
+    pub max_nodes: Option<usize>,
+    /// Reject inputs longer than this many bytes with [`InputTooLong`], before they are parsed, to bound the work of
+    /// parsing untrusted input. For the functions parsing several literals, the limit is on the whole input. `None`
+    /// places no limit.
+    ///
+    /// [`InputTooLong`]: crate::common::error::TypeQLError::InputTooLong
+    pub max_input_len: Option<usize>,
+    /// Reject string literals longer than this many bytes, including their quotes, with [`StringLiteralTooLong`], to
+    /// bound the memory a single literal in untrusted input can take. `None` places no limit.
+    ///
+    /// [`StringLiteralTooLong`]: crate::common::error::TypeQLError::StringLiteralTooLong
+    pub max_string_len: Option<usize>,
+    /// The grammar version to parse, [`Version::LATEST`] by default. Syntax introduced after this version, in queries
+    /// and literals alike, is rejected like any other invalid input, even when its option is enabled.
+    pub language_version: Version,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            allow_currency_and_percent: false,
+            allow_unit_suffixes: false,
+            allow_parenthesized_negatives: false,
+            allow_bare_decimal_points: false,
+            null_tokens: Vec::new(),
+            boolean_keywords: true,
+            allow_partial_dates: false,
+            allow_basic_dates: false,
+            allow_spaced_date_separators: false,
+            allow_week_dates: false,
+            allow_ordinal_dates: false,
+            allow_radix_integers: false,
+            allow_casts: false,
+            slash_date_order: None,
+            allow_epoch_seconds: false,
+            relative_datetime_now: None,
+            max_nodes: None,
+            max_input_len: None,
+            max_string_len: None,
+            language_version: Version::LATEST,
+        }
+    }
+}
@@ -15,7 +15,8 @@ use super::{
         visit_statement,
     },
     type_::{visit_label, visit_label_list},
-    visit_reduce_assignment_var, visit_var, visit_var_named, visit_vars, IntoChildNodes, Node, Rule, RuleMatcher,
+    visit_reduce_assignment_var, visit_var, visit_var_named, visit_vars, IntoChildNodes, Node, ParserOptions, Rule,
+    RuleMatcher,
 };
 use crate::{
     common::{
@@ -51,134 +52,143 @@ use crate::{
     TypeRef, TypeRefAny,
 };
 
-pub(super) fn visit_query_pipeline_preambled(node: Node<'_>) -> Pipeline {
+pub(super) fn visit_query_pipeline_preambled(node: Node<'_>, options: &ParserOptions) -> Pipeline {
     debug_assert_eq!(node.as_rule(), Rule::query_pipeline_preambled);
     let span = node.span();
     let mut children = node.into_children();
 
-    let preambles = children.take_while_ref(|child| child.as_rule() == Rule::preamble).map(visit_preamble).collect();
-    let stages = visit_query_pipeline(children.consume_expected(Rule::query_pipeline));
+    let preambles = children
+        .take_while_ref(|child| child.as_rule() == Rule::preamble)
+        .map(|node| visit_preamble(node, options))
+        .collect();
+    let stages = visit_query_pipeline(children.consume_expected(Rule::query_pipeline), options);
     debug_assert_eq!(children.try_consume_any(), None);
 
     Pipeline::new(span, preambles, stages)
 }
 
-fn visit_query_pipeline(node: Node<'_>) -> Vec<Stage> {
+fn visit_query_pipeline(node: Node<'_>, options: &ParserOptions) -> Vec<Stage> {
     debug_assert_eq!(node.as_rule(), Rule::query_pipeline);
     let mut children = node.into_children();
-    let mut stages =
-        children.take_while_ref(|child| child.as_rule() == Rule::query_stage).map(visit_query_stage).collect_vec();
-    stages.extend(children.try_consume_expected(Rule::query_stage_terminal).map(visit_query_stage_terminal));
+    let mut stages = children
+        .take_while_ref(|child| child.as_rule() == Rule::query_stage)
+        .map(|node| visit_query_stage(node, options))
+        .collect_vec();
+    stages.extend(
+        children.try_consume_expected(Rule::query_stage_terminal).map(|node| visit_query_stage_terminal(node, options)),
+    );
     debug_assert_eq!(children.try_consume_any(), None);
     stages
 }
 
-fn visit_preamble(node: Node<'_>) -> Preamble {
+fn visit_preamble(node: Node<'_>, options: &ParserOptions) -> Preamble {
     debug_assert_eq!(node.as_rule(), Rule::preamble);
     let span = node.span();
     let mut children = node.into_children();
-    let patterns =
-        visit_definition_function(children.skip_expected(Rule::WITH).consume_expected(Rule::definition_function));
+    let patterns = visit_definition_function(
+        children.skip_expected(Rule::WITH).consume_expected(Rule::definition_function),
+        options,
+    );
     debug_assert_eq!(children.try_consume_any(), None);
     Preamble::new(span, patterns)
 }
 
-pub(super) fn visit_query_stage(node: Node<'_>) -> Stage {
+pub(super) fn visit_query_stage(node: Node<'_>, options: &ParserOptions) -> Stage {
     debug_assert_eq!(node.as_rule(), Rule::query_stage);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::clause_match => Stage::Match(visit_clause_match(child)),
-        Rule::clause_insert => Stage::Insert(visit_clause_insert(child)),
-        Rule::clause_put => Stage::Put(visit_clause_put(child)),
-        Rule::clause_update => Stage::Update(visit_clause_update(child)),
+        Rule::clause_match => Stage::Match(visit_clause_match(child, options)),
+        Rule::clause_insert => Stage::Insert(visit_clause_insert(child, options)),
+        Rule::clause_put => Stage::Put(visit_clause_put(child, options)),
+        Rule::clause_update => Stage::Update(visit_clause_update(child, options)),
         Rule::clause_delete => Stage::Delete(visit_clause_delete(child)),
         Rule::operator_stream => Stage::Operator(visit_operator_stream(child)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-fn visit_query_stage_terminal(node: Node<'_>) -> Stage {
+fn visit_query_stage_terminal(node: Node<'_>, options: &ParserOptions) -> Stage {
     debug_assert_eq!(node.as_rule(), Rule::query_stage_terminal);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::clause_fetch => Stage::Fetch(visit_clause_fetch(child)),
+        Rule::clause_fetch => Stage::Fetch(visit_clause_fetch(child, options)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-pub(super) fn visit_clause_match(node: Node<'_>) -> Match {
+pub(super) fn visit_clause_match(node: Node<'_>, options: &ParserOptions) -> Match {
     debug_assert_eq!(node.as_rule(), Rule::clause_match);
     let span = node.span();
     let mut children = node.into_children();
-    let patterns = visit_patterns(children.skip_expected(Rule::MATCH).consume_expected(Rule::patterns));
+    let patterns = visit_patterns(children.skip_expected(Rule::MATCH).consume_expected(Rule::patterns), options);
     debug_assert_eq!(children.try_consume_any(), None);
     Match::new(span, patterns)
 }
 
-pub(super) fn visit_patterns(node: Node<'_>) -> Vec<Pattern> {
+pub(super) fn visit_patterns(node: Node<'_>, options: &ParserOptions) -> Vec<Pattern> {
     debug_assert_eq!(node.as_rule(), Rule::patterns);
-    node.into_children().map(visit_pattern).collect()
+    node.into_children().map(|node| visit_pattern(node, options)).collect()
 }
 
-pub(super) fn visit_pattern(node: Node<'_>) -> Pattern {
+pub(super) fn visit_pattern(node: Node<'_>, options: &ParserOptions) -> Pattern {
     debug_assert_eq!(node.as_rule(), Rule::pattern);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::pattern_conjunction => Pattern::Conjunction(visit_pattern_conjunction(child)),
-        Rule::pattern_disjunction => Pattern::Disjunction(visit_pattern_disjunction(child)),
-        Rule::pattern_negation => Pattern::Negation(visit_pattern_negation(child)),
-        Rule::pattern_try => Pattern::Optional(visit_pattern_try(child)),
-        Rule::statement => Pattern::Statement(visit_statement(child)),
+        Rule::pattern_conjunction => Pattern::Conjunction(visit_pattern_conjunction(child, options)),
+        Rule::pattern_disjunction => Pattern::Disjunction(visit_pattern_disjunction(child, options)),
+        Rule::pattern_negation => Pattern::Negation(visit_pattern_negation(child, options)),
+        Rule::pattern_try => Pattern::Optional(visit_pattern_try(child, options)),
+        Rule::statement => Pattern::Statement(visit_statement(child, options)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-fn visit_pattern_conjunction(node: Node<'_>) -> Conjunction {
+fn visit_pattern_conjunction(node: Node<'_>, options: &ParserOptions) -> Conjunction {
     debug_assert_eq!(node.as_rule(), Rule::pattern_conjunction);
     let span = node.span();
-    Conjunction::new(span, visit_patterns(node.into_child()))
+    Conjunction::new(span, visit_patterns(node.into_child(), options))
 }
 
-fn visit_pattern_disjunction(node: Node<'_>) -> Disjunction {
+fn visit_pattern_disjunction(node: Node<'_>, options: &ParserOptions) -> Disjunction {
     debug_assert_eq!(node.as_rule(), Rule::pattern_disjunction);
     let span = node.span();
     let mut branches = Vec::new();
     let mut children = node.into_children();
     while let Some(branch) = children.try_consume_expected(Rule::patterns) {
-        branches.push(visit_patterns(branch));
+        branches.push(visit_patterns(branch, options));
         children.try_consume_expected(Rule::OR);
     }
     debug_assert_eq!(children.try_consume_any(), None);
     Disjunction::new(span, branches)
 }
 
-fn visit_pattern_negation(node: Node<'_>) -> Negation {
+fn visit_pattern_negation(node: Node<'_>, options: &ParserOptions) -> Negation {
     debug_assert_eq!(node.as_rule(), Rule::pattern_negation);
     let span = node.span();
     let mut children = node.into_children();
     let patterns = children.skip_expected(Rule::NOT).consume_expected(Rule::patterns);
     debug_assert_eq!(children.try_consume_any(), None);
-    Negation::new(span, visit_patterns(patterns))
+    Negation::new(span, visit_patterns(patterns, options))
 }
 
-fn visit_pattern_try(node: Node<'_>) -> Optional {
+fn visit_pattern_try(node: Node<'_>, options: &ParserOptions) -> Optional {
     debug_assert_eq!(node.as_rule(), Rule::pattern_try);
     let span = node.span();
     let mut children = node.into_children();
     let patterns = children.skip_expected(Rule::TRY).consume_expected(Rule::patterns);
     debug_assert_eq!(children.try_consume_any(), None);
-    Optional::new(span, visit_patterns(patterns))
+    Optional::new(span, visit_patterns(patterns, options))
 }
 
-fn visit_clause_insert(node: Node<'_>) -> Insert {
+fn visit_clause_insert(node: Node<'_>, options: &ParserOptions) -> Insert {
     debug_assert_eq!(node.as_rule(), Rule::clause_insert);
     let span = node.span();
     let statements = node
         .into_children()
         .skip_expected(Rule::INSERT)
         .map(|child| match child.as_rule() {
-            Rule::statement_thing => visit_statement_thing(child),
-            Rule::statement_assignment => Statement::Assignment(visit_statement_assignment(child)),
+            Rule::statement_thing => visit_statement_thing(child, options),
+            Rule::statement_assignment => Statement::Assignment(visit_statement_assignment(child, options)),
             _ => unreachable!(
                 "Unrecognised statement inside insert clause: {:?}",
                 TypeQLError::IllegalGrammar { input: child.to_string() }
@@ -188,17 +198,19 @@ fn visit_clause_insert(node: Node<'_>) -> Insert {
     Insert::new(span, statements)
 }
 
-fn visit_clause_put(node: Node<'_>) -> Put {
+fn visit_clause_put(node: Node<'_>, options: &ParserOptions) -> Put {
     debug_assert_eq!(node.as_rule(), Rule::clause_put);
     let span = node.span();
-    let statement_things = node.into_children().skip_expected(Rule::PUT).map(visit_statement_thing).collect();
+    let statement_things =
+        node.into_children().skip_expected(Rule::PUT).map(|node| visit_statement_thing(node, options)).collect();
     Put::new(span, statement_things)
 }
 
-fn visit_clause_update(node: Node<'_>) -> Update {
+fn visit_clause_update(node: Node<'_>, options: &ParserOptions) -> Update {
     debug_assert_eq!(node.as_rule(), Rule::clause_update);
     let span = node.span();
-    let statement_things = node.into_children().skip_expected(Rule::UPDATE).map(visit_statement_thing).collect();
+    let statement_things =
+        node.into_children().skip_expected(Rule::UPDATE).map(|node| visit_statement_thing(node, options)).collect();
     Update::new(span, statement_things)
 }
 
@@ -237,33 +249,34 @@ fn visit_statement_deletable(node: Node<'_>) -> Deletable {
     Deletable::new(span, kind)
 }
 
-fn visit_clause_fetch(node: Node<'_>) -> Fetch {
+fn visit_clause_fetch(node: Node<'_>, options: &ParserOptions) -> Fetch {
     debug_assert_eq!(node.as_rule(), Rule::clause_fetch);
     let span = node.span();
     let mut children = node.into_children();
-    let fetch_object = visit_fetch_object(children.skip_expected(Rule::FETCH).consume_expected(Rule::fetch_object));
+    let fetch_object =
+        visit_fetch_object(children.skip_expected(Rule::FETCH).consume_expected(Rule::fetch_object), options);
     debug_assert_eq!(children.try_consume_any(), None);
     Fetch::new(span, fetch_object)
 }
 
-fn visit_fetch_some(node: Node<'_>) -> FetchSome {
+fn visit_fetch_some(node: Node<'_>, options: &ParserOptions) -> FetchSome {
     debug_assert_eq!(node.as_rule(), Rule::fetch_some);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::fetch_object => FetchSome::Object(visit_fetch_object(child)),
-        Rule::fetch_list => FetchSome::List(visit_fetch_list(child)),
-        Rule::fetch_single => FetchSome::Single(visit_fetch_single(child)),
+        Rule::fetch_object => FetchSome::Object(visit_fetch_object(child, options)),
+        Rule::fetch_list => FetchSome::List(visit_fetch_list(child, options)),
+        Rule::fetch_single => FetchSome::Single(visit_fetch_single(child, options)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-fn visit_fetch_single(node: Node<'_>) -> FetchSingle {
+fn visit_fetch_single(node: Node<'_>, options: &ParserOptions) -> FetchSingle {
     debug_assert_eq!(node.as_rule(), Rule::fetch_single);
     let child = node.into_child();
     match child.as_rule() {
         Rule::fetch_attribute => FetchSingle::Attribute(visit_fetch_attribute(child)),
-        Rule::function_block => FetchSingle::FunctionBlock(visit_function_block(child)),
-        Rule::expression => FetchSingle::Expression(visit_expression(child)),
+        Rule::function_block => FetchSingle::FunctionBlock(visit_function_block(child, options)),
+        Rule::expression => FetchSingle::Expression(visit_expression(child, options)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
@@ -283,20 +296,20 @@ fn visit_fetch_attribute(node: Node<'_>) -> FetchAttribute {
     FetchAttribute::new(span, owner, attribute)
 }
 
-fn visit_fetch_object(node: Node<'_>) -> FetchObject {
+fn visit_fetch_object(node: Node<'_>, options: &ParserOptions) -> FetchObject {
     debug_assert_eq!(node.as_rule(), Rule::fetch_object);
     let span = node.span();
     let mut children = node.into_children();
-    let body = visit_fetch_object_body(children.consume_expected(Rule::fetch_body));
+    let body = visit_fetch_object_body(children.consume_expected(Rule::fetch_body), options);
     FetchObject::new(span, body)
 }
 
-fn visit_fetch_object_body(node: Node<'_>) -> FetchObjectBody {
+fn visit_fetch_object_body(node: Node<'_>, options: &ParserOptions) -> FetchObjectBody {
     debug_assert_eq!(node.as_rule(), Rule::fetch_body);
     let child = node.into_child();
     match child.as_rule() {
         Rule::fetch_object_entries => {
-            let entries = child.into_children().map(visit_fetch_object_entry).collect();
+            let entries = child.into_children().map(|node| visit_fetch_object_entry(node, options)).collect();
             FetchObjectBody::Entries(entries)
         }
         Rule::fetch_attributes_all => {
@@ -307,12 +320,12 @@ fn visit_fetch_object_body(node: Node<'_>) -> FetchObjectBody {
     }
 }
 
-fn visit_fetch_object_entry(node: Node<'_>) -> FetchObjectEntry {
+fn visit_fetch_object_entry(node: Node<'_>, options: &ParserOptions) -> FetchObjectEntry {
     debug_assert_eq!(node.as_rule(), Rule::fetch_object_entry);
     let span = node.span();
     let mut children = node.into_children();
     let key = visit_fetch_key(children.consume_expected(Rule::fetch_key));
-    let value = visit_fetch_some(children.consume_expected(Rule::fetch_some));
+    let value = visit_fetch_some(children.consume_expected(Rule::fetch_some), options);
     debug_assert_eq!(children.try_consume_any(), None);
     FetchObjectEntry::new(span, key, value)
 }
@@ -322,21 +335,21 @@ fn visit_fetch_key(node: Node<'_>) -> StringLiteral {
     visit_quoted_string_literal(node.into_child())
 }
 
-fn visit_fetch_list(node: Node<'_>) -> FetchList {
+fn visit_fetch_list(node: Node<'_>, options: &ParserOptions) -> FetchList {
     debug_assert_eq!(node.as_rule(), Rule::fetch_list);
     let span = node.span();
-    let stream = visit_fetch_stream(node.into_child());
+    let stream = visit_fetch_stream(node.into_child(), options);
     FetchList::new(span, stream)
 }
 
-fn visit_fetch_stream(node: Node<'_>) -> FetchStream {
+fn visit_fetch_stream(node: Node<'_>, options: &ParserOptions) -> FetchStream {
     debug_assert_eq!(node.as_rule(), Rule::fetch_stream);
     let child = node.into_child();
     match child.as_rule() {
         Rule::fetch_attribute => FetchStream::Attribute(visit_fetch_attribute(child)),
-        Rule::function_block => FetchStream::SubQueryFunctionBlock(visit_function_block(child)),
-        Rule::query_pipeline => FetchStream::SubQueryFetch(visit_query_pipeline(child)),
-        Rule::expression_function => FetchStream::Function(visit_expression_function(child)),
+        Rule::function_block => FetchStream::SubQueryFunctionBlock(visit_function_block(child, options)),
+        Rule::query_pipeline => FetchStream::SubQueryFetch(visit_query_pipeline(child, options)),
+        Rule::expression_function => FetchStream::Function(visit_expression_function(child, options)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
@@ -5,7 +5,8 @@
  */
 
 use super::{
-    define::type_::visit_type_capability, type_::visit_label, visit_kind, IntoChildNodes, Node, Rule, RuleMatcher,
+    define::type_::visit_type_capability, type_::visit_label, visit_kind, IntoChildNodes, Node, ParserOptions, Rule,
+    RuleMatcher,
 };
 use crate::{
     common::Spanned,
@@ -14,23 +15,27 @@ use crate::{
     schema::definable::{Definable, Type},
 };
 
-pub(super) fn visit_query_redefine(node: Node<'_>) -> Redefine {
+pub(super) fn visit_query_redefine(node: Node<'_>, options: &ParserOptions) -> Redefine {
     debug_assert_eq!(node.as_rule(), Rule::query_redefine);
     let span = node.span();
-    let redefinables = node.into_children().skip_expected(Rule::REDEFINE).map(visit_redefinable).collect();
+    let redefinables =
+        node.into_children().skip_expected(Rule::REDEFINE).map(|node| visit_redefinable(node, options)).collect();
     Redefine::new(span, redefinables)
 }
 
-fn visit_redefinable(node: Node<'_>) -> Definable {
+fn visit_redefinable(node: Node<'_>, options: &ParserOptions) -> Definable {
     debug_assert_eq!(node.as_rule(), Rule::redefinable);
     let span = node.span();
     let mut children = node.into_children();
     let kind = children.try_consume_expected(Rule::kind).map(visit_kind);
     let label = visit_label(children.consume_expected(Rule::label));
-    let annotations = children.try_consume_expected(Rule::annotations).map(visit_annotations).unwrap_or_default();
+    let annotations = children
+        .try_consume_expected(Rule::annotations)
+        .map(|node| visit_annotations(node, options))
+        .unwrap_or_default();
     let capabilities = children
         .try_consume_expected(Rule::type_capability)
-        .map(|node| vec![visit_type_capability(node)])
+        .map(|node| vec![visit_type_capability(node, options)])
         .unwrap_or_default();
     debug_assert_eq!(children.try_consume_any(), None);
     Definable::TypeDeclaration(Type::new(span, kind, label, annotations, capabilities))
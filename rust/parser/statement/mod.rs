@@ -9,7 +9,8 @@ use self::{
     thing::{visit_statement_relation_anonymous, visit_statement_thing_var},
 };
 use super::{
-    expression::visit_expression_value, statement::type_::visit_statement_type, IntoChildNodes, Node, Rule, RuleMatcher,
+    expression::visit_expression_value, statement::type_::visit_statement_type, IntoChildNodes, Node, ParserOptions,
+    Rule, RuleMatcher,
 };
 use crate::{
     common::{error::TypeQLError, token::Comparator, Spanned},
@@ -20,19 +21,19 @@ pub(super) mod single;
 pub(super) mod thing;
 pub(super) mod type_;
 
-pub(super) fn visit_statement(node: Node<'_>) -> Statement {
+pub(super) fn visit_statement(node: Node<'_>, options: &ParserOptions) -> Statement {
     debug_assert_eq!(node.as_rule(), Rule::statement);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::statement_single => visit_statement_single(child),
-        Rule::statement_type => visit_statement_type(child),
-        Rule::statement_thing_var => visit_statement_thing_var(child),
-        Rule::statement_relation_anonymous => visit_statement_relation_anonymous(child),
+        Rule::statement_single => visit_statement_single(child, options),
+        Rule::statement_type => visit_statement_type(child, options),
+        Rule::statement_thing_var => visit_statement_thing_var(child, options),
+        Rule::statement_relation_anonymous => visit_statement_relation_anonymous(child, options),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-fn visit_comparison(node: Node<'_>) -> Comparison {
+fn visit_comparison(node: Node<'_>, options: &ParserOptions) -> Comparison {
     debug_assert_eq!(node.as_rule(), Rule::comparison);
     let span = node.span();
     let mut children = node.into_children();
@@ -48,7 +49,7 @@ fn visit_comparison(node: Node<'_>) -> Comparison {
         Rule::LIKE => Comparator::Like,
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: comparator_node.to_string() }),
     };
-    let rhs = visit_expression_value(children.consume_expected(Rule::expression_value));
+    let rhs = visit_expression_value(children.consume_expected(Rule::expression_value), options);
     debug_assert_eq!(children.try_consume_any(), None);
     Comparison::new(span, comparator, rhs)
 }
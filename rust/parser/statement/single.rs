@@ -12,7 +12,7 @@ use crate::{
     parser::{
         expression::{visit_expression, visit_expression_function, visit_expression_list, visit_expression_value},
         statement::visit_comparison,
-        visit_identifier, visit_var, visit_vars_assignment, IntoChildNodes, Node, Rule, RuleMatcher,
+        visit_identifier, visit_var, visit_vars_assignment, IntoChildNodes, Node, ParserOptions, Rule, RuleMatcher,
     },
     statement::{
         comparison::ComparisonStatement, Assignment, AssignmentPattern, DeconstructField, InIterable, Is, Statement,
@@ -20,25 +20,25 @@ use crate::{
     },
 };
 
-pub(super) fn visit_statement_single(node: Node<'_>) -> Statement {
+pub(super) fn visit_statement_single(node: Node<'_>, options: &ParserOptions) -> Statement {
     debug_assert_eq!(node.as_rule(), Rule::statement_single);
     let child = node.into_child();
     match child.as_rule() {
         Rule::statement_is => Statement::Is(visit_statement_is(child)),
-        Rule::statement_in => Statement::InIterable(visit_statement_in(child)),
-        Rule::statement_comparison => Statement::Comparison(visit_statement_comparison(child)),
-        Rule::statement_assignment => Statement::Assignment(visit_statement_assignment(child)),
+        Rule::statement_in => Statement::InIterable(visit_statement_in(child, options)),
+        Rule::statement_comparison => Statement::Comparison(visit_statement_comparison(child, options)),
+        Rule::statement_assignment => Statement::Assignment(visit_statement_assignment(child, options)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-pub fn visit_statement_assignment(node: Node<'_>) -> Assignment {
+pub fn visit_statement_assignment(node: Node<'_>, options: &ParserOptions) -> Assignment {
     debug_assert_eq!(node.as_rule(), Rule::statement_assignment);
     let span = node.span();
     let mut children = node.into_children();
     let lhs = visit_assignment_left(children.consume_expected(Rule::assignment_left));
     children.skip_expected(Rule::ASSIGN);
-    let rhs = visit_expression(children.consume_expected(Rule::expression));
+    let rhs = visit_expression(children.consume_expected(Rule::expression), options);
     debug_assert_eq!(children.try_consume_any(), None);
     Assignment::new(span, lhs, rhs)
 }
@@ -83,12 +83,12 @@ fn visit_struct_key(node: Node<'_>) -> Identifier {
     visit_identifier(node.into_child())
 }
 
-pub fn visit_statement_comparison(node: Node<'_>) -> ComparisonStatement {
+pub fn visit_statement_comparison(node: Node<'_>, options: &ParserOptions) -> ComparisonStatement {
     debug_assert_eq!(node.as_rule(), Rule::statement_comparison);
     let span = node.span();
     let mut children = node.into_children();
-    let lhs = visit_expression_value(children.consume_expected(Rule::expression_value));
-    let comparison = visit_comparison(children.consume_expected(Rule::comparison));
+    let lhs = visit_expression_value(children.consume_expected(Rule::expression_value), options);
+    let comparison = visit_comparison(children.consume_expected(Rule::comparison), options);
     debug_assert_eq!(children.try_consume_any(), None);
     ComparisonStatement::new(span, lhs, comparison)
 }
@@ -104,7 +104,7 @@ pub fn visit_statement_is(node: Node<'_>) -> Is {
     Is::new(span, lhs, rhs)
 }
 
-pub fn visit_statement_in(node: Node<'_>) -> InIterable {
+pub fn visit_statement_in(node: Node<'_>, options: &ParserOptions) -> InIterable {
     debug_assert_eq!(node.as_rule(), Rule::statement_in);
     let span = node.span();
     let mut children = node.into_children();
@@ -112,8 +112,8 @@ pub fn visit_statement_in(node: Node<'_>) -> InIterable {
     children.skip_expected(Rule::IN);
     let child = children.consume_any();
     let rhs = match child.as_rule() {
-        Rule::expression_function => Expression::Function(visit_expression_function(child)),
-        Rule::expression_list => visit_expression_list(child),
+        Rule::expression_function => Expression::Function(visit_expression_function(child, options)),
+        Rule::expression_list => visit_expression_list(child, options),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     };
     debug_assert_eq!(children.try_consume_any(), None);
@@ -12,7 +12,7 @@ use crate::{
         literal::visit_value_literal,
         statement::visit_comparison,
         type_::{visit_type_ref, visit_type_ref_list},
-        visit_var, IntoChildNodes, Node, Rule, RuleMatcher,
+        visit_var, IntoChildNodes, Node, ParserOptions, Rule, RuleMatcher,
     },
     statement::{
         thing::{
@@ -25,27 +25,29 @@ use crate::{
     type_::TypeRefAny,
 };
 
-pub(in crate::parser) fn visit_statement_thing(node: Node<'_>) -> Statement {
+pub(in crate::parser) fn visit_statement_thing(node: Node<'_>, options: &ParserOptions) -> Statement {
     debug_assert_eq!(node.as_rule(), Rule::statement_thing);
     let child = node.into_child();
     match child.as_rule() {
-        Rule::statement_thing_var => visit_statement_thing_var(child),
-        Rule::statement_relation_anonymous => visit_statement_relation_anonymous(child),
+        Rule::statement_thing_var => visit_statement_thing_var(child, options),
+        Rule::statement_relation_anonymous => visit_statement_relation_anonymous(child, options),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
 }
 
-pub(super) fn visit_statement_thing_var(node: Node<'_>) -> Statement {
+pub(super) fn visit_statement_thing_var(node: Node<'_>, options: &ParserOptions) -> Statement {
     debug_assert_eq!(node.as_rule(), Rule::statement_thing_var);
     let span = node.span();
     let mut children = node.into_children();
     let var = visit_var(children.consume_expected(Rule::var));
     match children.peek_rule().unwrap() {
-        Rule::thing_constraint => {
-            Statement::Thing(Thing::new(span, Head::Variable(var), children.map(visit_thing_constraint).collect()))
-        }
-        Rule::value_literal => {
-            let value = visit_value_literal(children.consume_expected(Rule::value_literal));
+        Rule::thing_constraint => Statement::Thing(Thing::new(
+            span,
+            Head::Variable(var),
+            children.map(|node| visit_thing_constraint(node, options)).collect(),
+        )),
+        Rule::query_value_literal => {
+            let value = visit_value_literal(children.consume_expected(Rule::query_value_literal), options);
             let isa = visit_isa_constraint(children.consume_expected(Rule::isa_constraint));
             debug_assert_eq!(children.try_consume_any(), None);
             Statement::AttributeValue(AttributeValueStatement::new(span, var, value, isa))
@@ -57,7 +59,7 @@ pub(super) fn visit_statement_thing_var(node: Node<'_>) -> Statement {
             Statement::AttributeValue(AttributeValueStatement::new(span, var, value, isa))
         }
         Rule::comparison => {
-            let comparison = visit_comparison(children.consume_expected(Rule::comparison));
+            let comparison = visit_comparison(children.consume_expected(Rule::comparison), options);
             let isa = visit_isa_constraint(children.consume_expected(Rule::isa_constraint));
             debug_assert_eq!(children.try_consume_any(), None);
             Statement::AttributeComparison(AttributeComparisonStatement::new(span, var, comparison, isa))
@@ -66,22 +68,22 @@ pub(super) fn visit_statement_thing_var(node: Node<'_>) -> Statement {
     }
 }
 
-pub(super) fn visit_statement_relation_anonymous(node: Node<'_>) -> Statement {
+pub(super) fn visit_statement_relation_anonymous(node: Node<'_>, options: &ParserOptions) -> Statement {
     debug_assert_eq!(node.as_rule(), Rule::statement_relation_anonymous);
     let span = node.span();
     let mut children = node.into_children();
     let head = Head::Relation(visit_relation(children.consume_expected(Rule::relation)));
-    let constraints = children.map(visit_thing_constraint).collect();
+    let constraints = children.map(|node| visit_thing_constraint(node, options)).collect();
     Statement::Thing(Thing::new(span, head, constraints))
 }
 
-fn visit_thing_constraint(node: Node<'_>) -> Constraint {
+fn visit_thing_constraint(node: Node<'_>, options: &ParserOptions) -> Constraint {
     debug_assert_eq!(node.as_rule(), Rule::thing_constraint);
     let child = node.into_child();
     match child.as_rule() {
         Rule::isa_constraint => Constraint::Isa(visit_isa_constraint(child)),
         Rule::iid_constraint => Constraint::Iid(visit_iid_constraint(child)),
-        Rule::has_constraint => Constraint::Has(visit_has_constraint(child)),
+        Rule::has_constraint => Constraint::Has(visit_has_constraint(child, options)),
         Rule::links_constraint => Constraint::Links(visit_links_constraint(child)),
         _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: child.to_string() }),
     }
@@ -116,7 +118,7 @@ fn visit_iid_constraint(node: Node<'_>) -> Iid {
     Iid::new(span, iid)
 }
 
-fn visit_has_constraint(node: Node<'_>) -> Has {
+fn visit_has_constraint(node: Node<'_>, options: &ParserOptions) -> Has {
     debug_assert_eq!(node.as_rule(), Rule::has_constraint);
     let span = node.span();
     let mut children = node.into_children();
@@ -128,8 +130,8 @@ fn visit_has_constraint(node: Node<'_>) -> Has {
             let type_ = Some(TypeRefAny::Type(visit_type_ref(child)));
             let value_node = children.consume_any();
             let value = match value_node.as_rule() {
-                Rule::comparison => HasValue::Comparison(visit_comparison(value_node)),
-                Rule::expression_value => match visit_expression_value(value_node) {
+                Rule::comparison => HasValue::Comparison(visit_comparison(value_node, options)),
+                Rule::expression_value => match visit_expression_value(value_node, options) {
                     Expression::Variable(variable) => HasValue::Variable(variable),
                     expr => HasValue::Expression(expr),
                 },
@@ -142,8 +144,8 @@ fn visit_has_constraint(node: Node<'_>) -> Has {
             let value_node = children.consume_any();
             let value = match value_node.as_rule() {
                 Rule::var => HasValue::Variable(visit_var(value_node)),
-                Rule::comparison => HasValue::Comparison(visit_comparison(value_node)),
-                Rule::expression_list => HasValue::Expression(visit_expression_list(value_node)),
+                Rule::comparison => HasValue::Comparison(visit_comparison(value_node, options)),
+                Rule::expression_list => HasValue::Expression(visit_expression_list(value_node, options)),
                 _ => unreachable!("{}", TypeQLError::IllegalGrammar { input: value_node.to_string() }),
             };
             Has::new(span, type_, value)
@@ -11,7 +11,7 @@ use crate::{
         type_::{
             visit_label, visit_label_scoped, visit_type_ref, visit_type_ref_any, visit_type_ref_list, visit_value_type,
         },
-        visit_kind, IntoChildNodes, Node, Rule, RuleMatcher,
+        visit_kind, IntoChildNodes, Node, ParserOptions, Rule, RuleMatcher,
     },
     statement::{
         type_::{Constraint, ConstraintBase, LabelConstraint, Owns, Plays, Relates, Sub, SubKind, ValueType},
@@ -20,22 +20,25 @@ use crate::{
     type_::TypeRefAny,
 };
 
-pub(super) fn visit_statement_type(node: Node<'_>) -> Statement {
+pub(super) fn visit_statement_type(node: Node<'_>, options: &ParserOptions) -> Statement {
     debug_assert_eq!(node.as_rule(), Rule::statement_type);
     let span = node.span();
     let mut children = node.into_children();
     let kind = children.try_consume_expected(Rule::kind).map(visit_kind);
     let type_ = visit_type_ref_any(children.consume_expected(Rule::type_ref_any));
-    let constraints = children.map(visit_type_constraint).collect();
+    let constraints = children.map(|node| visit_type_constraint(node, options)).collect();
     Statement::Type(Type::new(span, kind, type_, constraints))
 }
 
-fn visit_type_constraint(node: Node<'_>) -> Constraint {
+fn visit_type_constraint(node: Node<'_>, options: &ParserOptions) -> Constraint {
     debug_assert_eq!(node.as_rule(), Rule::type_constraint);
     let span = node.span();
     let mut children = node.into_children();
     let base = visit_type_constraint_base(children.consume_expected(Rule::type_constraint_base));
-    let annotations = children.try_consume_expected(Rule::annotations).map(visit_annotations).unwrap_or_default();
+    let annotations = children
+        .try_consume_expected(Rule::annotations)
+        .map(|node| visit_annotations(node, options))
+        .unwrap_or_default();
     debug_assert_eq!(children.try_consume_any(), None);
     Constraint::new(span, base, annotations)
 }
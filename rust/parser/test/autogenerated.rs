@@ -13,7 +13,11 @@ use std::{
 use itertools::Itertools;
 use proc_macro2::{Delimiter, TokenStream, TokenTree};
 
-use crate::{parse_definition_function, parse_definition_struct};
+use crate::{
+    parse_definition_function, parse_definition_struct, parse_value_literal, parse_value_literal_with_options,
+    parser::{DateOrder, ParserOptions, Version},
+    value::ValueLiteral,
+};
 #[allow(unused)]
 use crate::{
     parse_label, parse_query,
@@ -285,6 +289,55 @@ fn all_rules_covered_by_visitors() {
         ("eof_label", (|s| parse_label(s).map(|_| ()).unwrap()) as fn(&str)),
         ("eof_definition_function", (|s| parse_definition_function(s).map(|_| ()).unwrap()) as fn(&str)),
         ("eof_definition_struct", (|s| parse_definition_struct(s).map(|_| ()).unwrap()) as fn(&str)),
+        ("eof_value_literal", (|s| parse_value_literal(s).map(|_| ()).unwrap()) as fn(&str)),
+        (
+            "eof_value_literal_lenient",
+            (|s| {
+                // unknown unit suffixes, nonexistent week or ordinal dates and impossible casts, including of strings
+                // with invalid escapes, are well-formed, but rejected by the visitor
+                if let Err(error) = parse_value_literal_with_options(s, &LENIENT) {
+                    assert!(
+                        ["[TQL06]", "[TQL41]", "[TQL47]", "[TQL53]", "[TQL56]", "[TQL59]", "[TQL65]"]
+                            .iter()
+                            .any(|code| error.to_string().contains(code)),
+                        "{error}"
+                    );
+                }
+            }) as fn(&str),
+        ),
+        (
+            "eof_range_literal",
+            (|s| {
+                // the bounds are generated independently, so usually have different types
+                if let Err(error) = parse_value_literal(s) {
+                    assert!(error.to_string().contains("[TQL66]"), "{error}");
+                }
+            }) as fn(&str),
+        ),
+        (
+            "eof_duplicate_timezone",
+            (|s| {
+                let error = parse_value_literal(s).unwrap_err();
+                assert!(error.to_string().contains("[TQL55]"), "{error}");
+            }) as fn(&str),
+        ),
+        (
+            "eof_misdelimited_timezone",
+            (|s| {
+                let error = parse_value_literal(s).unwrap_err();
+                assert!(error.to_string().contains("[TQL74]"), "{error}");
+            }) as fn(&str),
+        ),
+        (
+            "eof_basic_date",
+            (|s| assert!(matches!(parse_value_literal_with_options(s, &LENIENT).unwrap().inner, ValueLiteral::Date(_))))
+                as fn(&str),
+        ),
+        (
+            "eof_partial_date",
+            (|s| assert!(matches!(parse_value_literal_with_options(s, &LENIENT).unwrap().inner, ValueLiteral::Date(_))))
+                as fn(&str),
+        ),
     ]
     .into();
 
@@ -300,6 +353,29 @@ fn all_rules_covered_by_visitors() {
     }
 }
 
+const LENIENT: ParserOptions = ParserOptions {
+    allow_currency_and_percent: true,
+    allow_unit_suffixes: true,
+    allow_parenthesized_negatives: true,
+    allow_bare_decimal_points: true,
+    null_tokens: Vec::new(),
+    boolean_keywords: true,
+    allow_partial_dates: true,
+    allow_basic_dates: true,
+    allow_spaced_date_separators: true,
+    allow_week_dates: true,
+    allow_ordinal_dates: true,
+    allow_radix_integers: true,
+    allow_casts: true,
+    slash_date_order: Some(DateOrder::YMD),
+    allow_epoch_seconds: true,
+    relative_datetime_now: None,
+    max_nodes: None,
+    max_input_len: None,
+    max_string_len: None,
+    language_version: Version::LATEST,
+};
+
 fn bad_rng() -> u64 {
     let mut hasher = std::hash::DefaultHasher::new();
     std::time::Instant::now().hash(&mut hasher);
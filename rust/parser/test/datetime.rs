@@ -0,0 +1,850 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use chrono::{NaiveDate, NaiveTime, Weekday};
+
+use super::assert_full_span_coverage;
+use crate::{
+    common::{error::TypeQLError, LineColumn, Span, Spanned},
+    parse_value_literal, parse_value_literal_with_options,
+    parser::{DateOrder, ParserOptions},
+    value::{
+        DateFragment, DateLiteral, DateTimeLiteral, DateTimeTZLiteral, Day, FractionPolicy, Month, TimeFragment,
+        TimeZone, ValidationOptions, ValueLiteral, ValueType, Year,
+    },
+    Literal,
+};
+
+fn parse_datetime(literal: &str) -> (Literal, DateTimeLiteral) {
+    let parsed = parse_value_literal(literal).unwrap();
+    assert_full_span_coverage(&parsed, literal);
+    let ValueLiteral::DateTime(date_time) = parsed.inner.clone() else { panic!("expected a datetime: {parsed:?}") };
+    (parsed, date_time)
+}
+
+#[test]
+fn minute_sixty_is_always_rejected() {
+    let (mut literal, date_time) = parse_datetime("2024-01-01T10:59");
+    assert_eq!(date_time.time.as_naive_time(), Ok(NaiveTime::from_hms_opt(10, 59, 0).unwrap()));
+    assert!(literal.validate(&ValidationOptions::default()).is_ok());
+
+    let time = TimeFragment { minute: "60".to_owned(), ..date_time.time.clone() };
+    let minute = |span| TypeQLError::ComponentOutOfRange { component: "minute", value: 60, range: 0..=59, span };
+    assert_eq!(time.as_naive_time(), Err(minute(None).into()));
+    literal.inner = ValueLiteral::DateTime(DateTimeLiteral { time, ..date_time });
+    let options = ValidationOptions { allow_leap_seconds: true, ..Default::default() };
+    assert_eq!(literal.validate(&options), Err(minute(literal.span()).into()));
+    assert!(parse_value_literal("2024-01-01T10:60").is_err());
+}
+
+#[test]
+fn date_fragment_is_extracted_from_each_temporal_kind() {
+    let date = |literal: &str| parse_value_literal(literal).unwrap().as_date_fragment().map(ToString::to_string);
+    assert_eq!(date("2024-03-01").as_deref(), Some("2024-03-01"));
+    assert_eq!(date("2024-03-01T10:00:00").as_deref(), Some("2024-03-01"));
+    assert_eq!(date("2024-03-01T23:30-05:00").as_deref(), Some("2024-03-01"), "the local date, not the UTC date");
+    assert_eq!(date("2024-03-01T00:30 Asia/Tokyo").as_deref(), Some("2024-03-01"));
+    assert_eq!(date("20240301"), None);
+    assert_eq!(date("P1D"), None);
+}
+
+#[test]
+fn validation_names_the_out_of_range_component() {
+    let (parsed, date_time) = parse_datetime("2024-02-10T10:20:30");
+    let out_of_range = |component, value, range| {
+        Err(TypeQLError::ComponentOutOfRange { component, value, range, span: parsed.span() }.into())
+    };
+    let with_date = |month: &str, day: &str| {
+        let date = DateFragment { month: Some(month.to_owned()), day: Some(day.to_owned()), ..date_time.date.clone() };
+        Literal::new(parsed.span(), ValueLiteral::DateTime(DateTimeLiteral { date, ..date_time.clone() }))
+    };
+    let with_time = |hour: &str, minute: &str, second: &str| {
+        let time = TimeFragment {
+            hour: hour.to_owned(),
+            minute: minute.to_owned(),
+            second: Some(second.to_owned()),
+            second_fraction: None,
+        };
+        Literal::new(parsed.span(), ValueLiteral::DateTime(DateTimeLiteral { time, ..date_time.clone() }))
+    };
+    let options = ValidationOptions::default();
+    assert_eq!(with_date("13", "10").validate(&options), out_of_range("month", 13, 1..=12));
+    assert_eq!(with_date("00", "10").validate(&options), out_of_range("month", 0, 1..=12));
+    assert_eq!(with_date("02", "30").validate(&options), out_of_range("day", 30, 1..=29));
+    assert_eq!(with_date("04", "31").validate(&options), out_of_range("day", 31, 1..=30));
+    assert_eq!(with_time("24", "00", "00").validate(&options), out_of_range("hour", 24, 0..=23));
+    assert_eq!(with_time("10", "75", "00").validate(&options), out_of_range("minute", 75, 0..=59));
+    assert_eq!(with_time("10", "20", "61").validate(&options), out_of_range("second", 61, 0..=59));
+    let leap_seconds = ValidationOptions { allow_leap_seconds: true, ..Default::default() };
+    assert_eq!(with_time("10", "20", "61").validate(&leap_seconds), out_of_range("second", 61, 0..=60));
+    assert!(with_time("23", "59", "60").validate(&leap_seconds).is_ok());
+    assert!(with_date("02", "29").validate(&options).is_ok());
+
+    let error = with_date("13", "10").validate(&options).unwrap_err();
+    assert!(error.to_string().contains("The month '13' is outside its range of 1..=12."), "{error}");
+}
+
+#[test]
+fn fraction_precision_is_limited_by_validation() {
+    let options = ValidationOptions { max_fraction_precision: Some(6), ..Default::default() };
+    for (literal, precision) in
+        [("2024-01-01T10:00:00", 0), ("2024-01-01T10:00:00.1", 1), ("2024-01-01T10:00:00.123", 3)]
+    {
+        let (parsed, date_time) = parse_datetime(literal);
+        assert_eq!(date_time.time.fraction_precision(), precision);
+        assert!(parsed.validate(&options).is_ok(), "{literal}");
+    }
+    let (parsed, date_time) = parse_datetime("2024-01-01T10:00:00.1234567");
+    assert_eq!(date_time.time.fraction_precision(), 7);
+    let error = TypeQLError::FractionTooPrecise { precision: 7, max: 6, span: parsed.span() };
+    assert_eq!(parsed.validate(&options), Err(error.into()));
+    assert!(parsed.validate(&ValidationOptions::default()).is_ok());
+}
+
+#[test]
+fn normalized_fraction_trims_trailing_zeros() {
+    for (literal, fraction, normalized) in [
+        ("2024-01-01T10:00:00.100", Some("100"), Some("1")),
+        ("2024-01-01T10:00:00.120000000", Some("120000000"), Some("12")),
+        ("2024-01-01T10:00:00.0", Some("0"), None),
+        ("2024-01-01T10:00:00", None, None),
+    ] {
+        let (_, date_time) = parse_datetime(literal);
+        assert_eq!(date_time.time.second_fraction.as_deref(), fraction, "{literal}");
+        assert_eq!(date_time.time.normalized_fraction().as_deref(), normalized, "{literal}");
+    }
+}
+
+#[test]
+fn leap_second_is_detected() {
+    let (_, leap) = parse_datetime("2016-12-31T23:59:60");
+    assert!(leap.time.is_leap_second());
+    let (_, regular) = parse_datetime("2016-12-31T23:59:59");
+    assert!(!regular.time.is_leap_second());
+}
+
+#[test]
+fn leap_second_is_rejected_when_not_allowed() {
+    let (literal, _) = parse_datetime("2016-12-31T23:59:60.5");
+    let options = ValidationOptions { allow_leap_seconds: false, ..Default::default() };
+    assert_eq!(literal.validate(&options), Err(TypeQLError::LeapSecondNotAllowed { span: literal.span() }.into()));
+    assert!(literal.validate(&ValidationOptions::default()).is_err());
+}
+
+#[test]
+fn leap_second_is_accepted_and_converted_when_allowed() {
+    let (literal, date_time) = parse_datetime("2016-12-31T23:59:60.5");
+    let options = ValidationOptions { allow_leap_seconds: true, ..Default::default() };
+    assert_eq!(literal.validate(&options), Ok(()));
+    let expected = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+    assert_eq!(date_time.time.as_naive_time().unwrap(), expected);
+}
+
+#[test]
+fn subsecond_precision_is_detected_and_warned_about() {
+    let options = ValidationOptions { warn_on_subsecond: true, ..Default::default() };
+    for literal in ["2024-01-01T10:00:00.5", "2024-01-01T10:00:00.000 Europe/London"] {
+        let parsed = parse_value_literal(literal).unwrap();
+        match &parsed.inner {
+            ValueLiteral::DateTime(date_time) => assert!(date_time.has_subsecond()),
+            ValueLiteral::DateTimeTz(date_time) => assert!(date_time.has_subsecond()),
+            _ => panic!("expected a datetime: {parsed:?}"),
+        }
+        let mut warnings = Vec::new();
+        assert_eq!(parsed.validate_with_sink(&options, |warning| warnings.push(warning)), Ok(()));
+        assert_eq!(warnings, [TypeQLError::SubsecondPrecision { literal: literal.to_owned(), span: parsed.span() }]);
+        assert_eq!(parsed.validate(&options), Ok(()));
+    }
+
+    for literal in ["2024-01-01T10:00:00", "2024-01-01T10:00Z", "2024-01-01"] {
+        let parsed = parse_value_literal(literal).unwrap();
+        let mut warnings = Vec::new();
+        assert_eq!(parsed.validate_with_sink(&options, |warning| warnings.push(warning)), Ok(()));
+        assert_eq!(warnings, []);
+    }
+    let (_, date_time) = parse_datetime("2024-01-01T10:00:00");
+    assert!(!date_time.has_subsecond());
+}
+
+#[cfg(feature = "chrono-tz")]
+fn parse_datetime_tz(literal: &str) -> DateTimeTZLiteral {
+    let parsed = parse_value_literal(literal).unwrap();
+    assert_full_span_coverage(&parsed, literal);
+    let ValueLiteral::DateTimeTz(date_time) = parsed.inner else { panic!("expected a datetime-tz: {parsed:?}") };
+    date_time
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn unix_timestamp_resolves_time_zone_to_utc() {
+    assert_eq!(parse_datetime_tz("2023-06-01T12:00:00 Europe/London").to_unix_timestamp(), Ok((1685617200, 0)));
+    assert_eq!(parse_datetime_tz("2023-06-01T13:00:00.25+02:00").to_unix_timestamp(), Ok((1685617200, 250_000_000)));
+    assert_eq!(parse_datetime_tz("1970-01-01T00:00Z").to_unix_timestamp(), Ok((0, 0)));
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn unix_timestamp_rejects_local_times_around_daylight_saving_transitions() {
+    // clocks in London go back from 02:00 BST to 01:00 GMT, so 01:30 happens twice
+    let ambiguous = parse_datetime_tz("2023-10-29T01:30:00 Europe/London");
+    assert_eq!(
+        ambiguous.to_unix_timestamp(),
+        Err(TypeQLError::AmbiguousLocalTime {
+            date_time: "2023-10-29 01:30:00".to_owned(),
+            time_zone: "Europe/London".to_owned()
+        }
+        .into())
+    );
+
+    // clocks in London go forward from 01:00 GMT to 02:00 BST, so 01:30 never happens
+    let skipped = parse_datetime_tz("2023-03-26T01:30:00 Europe/London");
+    assert!(skipped.to_unix_timestamp().unwrap_err().to_string().contains("does not exist"));
+
+    let unaffected = parse_datetime_tz("2023-10-29T03:30:00 Europe/London");
+    assert_eq!(unaffected.to_unix_timestamp(), Ok((1698550200, 0)));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn dates_and_times_convert_to_the_time_crate() {
+    let date = |date: &str| date.parse::<DateFragment>().unwrap();
+    assert_eq!(
+        date("2024-02-29").as_time_date(),
+        Ok(time::Date::from_calendar_date(2024, time::Month::February, 29).unwrap())
+    );
+    assert_eq!(
+        date("2023-02-29").as_time_date(),
+        Err(TypeQLError::InvalidDate { date: "2023-02-29".to_owned() }.into())
+    );
+
+    let time = |time: &str| time.parse::<TimeFragment>().unwrap();
+    assert_eq!(time("23:59:59.5").as_time_time(), Ok(time::Time::from_hms_milli(23, 59, 59, 500).unwrap()));
+    assert_eq!(time("09:30").as_time_time(), Ok(time::Time::from_hms(9, 30, 0).unwrap()));
+    assert_eq!(time("23:59:60").as_time_time(), Err(TypeQLError::InvalidTime { time: "23:59:60".to_owned() }.into()));
+
+    let zoned = |date_time: &str| date_time.parse::<DateTimeTZLiteral>().unwrap().as_time_offset_datetime();
+    let instant = zoned("2023-06-01T13:00:00.25+02:00").unwrap();
+    assert_eq!((instant.unix_timestamp(), instant.nanosecond()), (1685617200, 250_000_000));
+    assert_eq!(instant.offset(), time::UtcOffset::from_hms(2, 0, 0).unwrap());
+    assert_eq!(zoned("1970-01-01T00:00Z").unwrap(), time::OffsetDateTime::UNIX_EPOCH);
+    let error = zoned("2023-06-01T12:00:00 Europe/London").unwrap_err();
+    assert_eq!(error, TypeQLError::UnsupportedIANATimeZone { name: "Europe/London".to_owned() }.into());
+    assert!(error.to_string().contains("chrono-tz"), "{error}");
+}
+
+#[test]
+fn datetime_tz_displays_as_written() {
+    for literal in ["2024-03-10T02:30:00 America/New_York", "2024-03-10T02:30:00.125+05:30", "2024-03-10T02:30Z"] {
+        assert_eq!(parse_value_literal(literal).unwrap().to_string(), literal);
+    }
+}
+
+#[test]
+fn year_window_is_enforced_at_the_boundaries() {
+    let options = ValidationOptions { min_year: Some(1), max_year: Some(9999), ..Default::default() };
+    let (below, _) = parse_datetime("0000-01-01T00:00");
+    assert_eq!(
+        below.validate(&options),
+        Err(TypeQLError::YearOutOfRange { year: "0000".to_owned(), span: below.span() }.into())
+    );
+    let (top, _) = parse_datetime("9999-12-31T23:59:59");
+    assert_eq!(top.validate(&options), Ok(()));
+    let (above, _) = parse_datetime("+10000-01-01T00:00");
+    assert!(above.validate(&options).is_err());
+    assert_eq!(above.validate(&ValidationOptions::default()), Ok(()));
+
+    let date = parse_value_literal("0000-06-15").unwrap();
+    assert!(date.validate(&options).is_err());
+}
+
+#[test]
+fn datetime_is_within_inclusive_bounds() {
+    let min: DateFragment = "0001-01-01".parse().unwrap();
+    let max: DateFragment = "9999-12-31".parse().unwrap();
+    assert_eq!(parse_datetime("0000-12-31T23:59").1.is_within(&min, &max), Ok(false));
+    assert_eq!(parse_datetime("0001-01-01T00:00").1.is_within(&min, &max), Ok(true));
+    assert_eq!(parse_datetime("9999-12-31T23:59").1.is_within(&min, &max), Ok(true));
+    assert_eq!(parse_datetime("+10000-01-01T00:00").1.is_within(&min, &max), Ok(false));
+}
+
+#[test]
+fn weekday_and_day_of_year_of_known_dates() {
+    let date = |date: &str| date.parse::<DateFragment>().unwrap();
+    assert_eq!(date("2000-01-01").weekday(), Ok(Weekday::Sat));
+    assert_eq!(date("2024-02-29").weekday(), Ok(Weekday::Thu));
+    assert_eq!(date("2000-01-01").day_of_year(), Ok(1));
+    assert_eq!(date("2024-12-31").day_of_year(), Ok(366));
+    assert_eq!(date("2023-12-31").day_of_year(), Ok(365));
+    assert_eq!(date("2023-02-29").weekday(), Err(TypeQLError::InvalidDate { date: "2023-02-29".to_owned() }.into()));
+}
+
+#[test]
+fn humanize_relative_dates() {
+    let date = |date: &str| date.parse::<DateFragment>().unwrap();
+    let now = date("2024-03-01");
+    assert_eq!(date("2024-03-01").humanize_relative(&now).unwrap(), "today");
+    assert_eq!(date("2024-02-29").humanize_relative(&now).unwrap(), "yesterday");
+    assert_eq!(date("2024-03-02").humanize_relative(&now).unwrap(), "tomorrow");
+    assert_eq!(date("2024-02-23").humanize_relative(&now).unwrap(), "7 days ago");
+    assert_eq!(date("2024-03-08").humanize_relative(&now).unwrap(), "in 7 days");
+    assert!(date("2023-02-29").humanize_relative(&now).is_err());
+}
+
+#[test]
+fn timezone_delimiters_match_the_zone_kind() {
+    for literal in ["2024-01-01T10:00 Europe/London", "2024-01-01T10:00+05:00", "2024-01-01T10:00Z"] {
+        let parsed = parse_value_literal(literal).unwrap();
+        assert!(matches!(parsed.inner, ValueLiteral::DateTimeTz(_)), "{literal}");
+        assert_eq!(parsed.to_string(), literal);
+    }
+    for literal in [
+        "2024-01-01T10:00Europe/London",
+        "2024-01-01T10:00 +05:00",
+        "2024-01-01T10:00 Z",
+        "2024-01-01T10:00[Europe/London]",
+        "2024-01-01T10:00 [Europe/London]",
+        "2024-01-01T10:00[+05:00]",
+    ] {
+        let error = parse_value_literal(literal).unwrap_err();
+        let zone = &literal["2024-01-01T10:00".len()..];
+        let column = "2024-01-01T10:00".len() as u32 + 1;
+        let span = Span {
+            begin: LineColumn { line: 1, column },
+            end: LineColumn { line: 1, column: column + zone.chars().count() as u32 },
+        };
+        let expected =
+            TypeQLError::MisdelimitedTimeZone { literal: literal.to_owned(), zone: zone.to_owned(), span: Some(span) };
+        assert_eq!(error, expected.into(), "{literal}");
+    }
+}
+
+#[test]
+fn z_designator_is_utc() {
+    let ValueLiteral::DateTimeTz(utc) = parse_value_literal("2024-01-01T10:00Z").unwrap().inner else { panic!() };
+    assert_eq!(utc.timezone, TimeZone::Utc);
+    assert_eq!(utc.timezone.to_string(), "Z");
+    assert_eq!(utc.timezone.iso_offset_seconds(), Some(0));
+
+    let ValueLiteral::DateTimeTz(offset) = parse_value_literal("2024-01-01T10:00+00:00").unwrap().inner else {
+        panic!()
+    };
+    assert_eq!(offset.timezone, TimeZone::ISO("+00:00".to_owned()));
+    assert_eq!(offset.timezone.iso_offset_seconds(), Some(0));
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn same_instant_in_different_zones_has_the_same_utc_string() {
+    let london = parse_datetime_tz("2024-06-01T12:00:00.5 Europe/London");
+    let kolkata = parse_datetime_tz("2024-06-01T16:30:00.500+05:30");
+    let utc = parse_datetime_tz("2024-06-01T11:00:00.5Z");
+    assert_eq!(london.to_utc_string(), Ok("2024-06-01T11:00:00.500Z".to_owned()));
+    assert_eq!(kolkata.to_utc_string(), london.to_utc_string());
+    assert_eq!(utc.to_utc_string(), london.to_utc_string());
+
+    assert!(parse_datetime_tz("2023-10-29T01:30 Europe/London").to_utc_string().is_err());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn iana_timezone_resolves_to_its_offset_at_a_datetime() {
+    let london = TimeZone::IANA("Europe/London".to_owned());
+    let (_, summer) = parse_datetime("2024-07-01T12:00");
+    let (_, winter) = parse_datetime("2024-01-15T12:00");
+    assert_eq!(london.to_fixed_offset(&summer), Ok(TimeZone::ISO("+01:00".to_owned())));
+    assert_eq!(london.to_fixed_offset(&winter), Ok(TimeZone::ISO("+00:00".to_owned())));
+    assert_eq!(
+        TimeZone::IANA("America/St_Johns".to_owned()).to_fixed_offset(&summer),
+        Ok(TimeZone::ISO("-02:30".to_owned()))
+    );
+
+    let offset = TimeZone::ISO("-05:00".to_owned());
+    assert_eq!(offset.to_fixed_offset(&summer), Ok(offset));
+    assert_eq!(TimeZone::Utc.to_fixed_offset(&summer), Ok(TimeZone::Utc));
+
+    let (_, skipped) = parse_datetime("2024-03-31T01:30");
+    assert!(london.to_fixed_offset(&skipped).is_err());
+    assert!(TimeZone::IANA("Mars/Olympus".to_owned()).to_fixed_offset(&summer).is_err());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn timezones_resolve_the_same_only_when_their_offsets_agree() {
+    let new_york = TimeZone::IANA("America/New_York".to_owned());
+    let eastern_standard = TimeZone::ISO("-05:00".to_owned());
+    let (_, winter) = parse_datetime("2024-01-15T12:00");
+    let (_, summer) = parse_datetime("2024-07-01T12:00");
+    assert_eq!(new_york.resolves_same_as(&eastern_standard, &winter), Ok(true));
+    assert_eq!(new_york.resolves_same_as(&eastern_standard, &summer), Ok(false));
+    assert_eq!(new_york.resolves_same_as(&TimeZone::ISO("-0400".to_owned()), &summer), Ok(true));
+    assert_eq!(new_york.resolves_same_as(&TimeZone::IANA("America/Toronto".to_owned()), &summer), Ok(true));
+
+    let london = TimeZone::IANA("Europe/London".to_owned());
+    assert_eq!(london.resolves_same_as(&TimeZone::Utc, &winter), Ok(true));
+    assert_eq!(TimeZone::Utc.resolves_same_as(&TimeZone::ISO("+00:00".to_owned()), &summer), Ok(true));
+    assert!(london.resolves_same_as(&TimeZone::IANA("Mars/Olympus".to_owned()), &winter).is_err());
+}
+
+#[test]
+fn standalone_timezones_parse_as_timezone_literals() {
+    for (literal, expected, canonical) in [
+        ("Europe/London", TimeZone::IANA("Europe/London".to_owned()), "Europe/London"),
+        (
+            "America/Argentina/Buenos_Aires",
+            TimeZone::IANA("America/Argentina/Buenos_Aires".to_owned()),
+            "America/Argentina/Buenos_Aires",
+        ),
+        ("+01:00", TimeZone::ISO("+01:00".to_owned()), "+01:00"),
+        ("-00:00", TimeZone::ISO("-00:00".to_owned()), "Z"),
+        ("Z", TimeZone::Utc, "Z"),
+    ] {
+        let parsed = parse_value_literal(literal).unwrap();
+        assert_full_span_coverage(&parsed, literal);
+        assert_eq!(parsed.value_type(), ValueType::TimeZone);
+        assert_eq!(parsed.as_timezone(), Some(&expected));
+        assert_eq!((parsed.to_string().as_str(), parsed.to_canonical_string().as_str()), (literal, canonical));
+    }
+    assert_eq!(parse_value_literal("+01").unwrap().value_type(), ValueType::Integer);
+    assert_eq!(parse_value_literal("+0100").unwrap().value_type(), ValueType::Integer);
+    assert!(parse_value_literal("UTC").is_err(), "a standalone IANA name needs an area");
+}
+
+#[test]
+fn partial_dates_are_only_accepted_when_enabled() {
+    let options = ParserOptions { allow_partial_dates: true, ..Default::default() };
+    let partial = |literal| match parse_value_literal_with_options(literal, &options).unwrap().inner {
+        ValueLiteral::Date(date) => date.date,
+        other => panic!("expected a date: {other:?}"),
+    };
+
+    let year = partial("2023");
+    assert_eq!((year.month.as_deref(), year.day.as_deref()), (None, None));
+    assert_eq!(year.to_string(), "2023");
+    assert_eq!(year.as_naive_date().unwrap().to_string(), "2023-01-01");
+
+    let month = partial("2023-06");
+    assert_eq!((month.month.as_deref(), month.day.as_deref()), (Some("06"), None));
+    assert_eq!(month.as_naive_date().unwrap().to_string(), "2023-06-01");
+
+    let full = partial("2023-06-15");
+    assert_eq!((full.month.as_deref(), full.day.as_deref()), (Some("06"), Some("15")));
+    assert!(parse_value_literal_with_options("2023-13", &options).is_err());
+    assert!(matches!(parse_value_literal_with_options("-2023", &options).unwrap().inner, ValueLiteral::Integer(_)));
+
+    assert!(matches!(parse_value_literal("2023").unwrap().inner, ValueLiteral::Integer(_)));
+    assert!(parse_value_literal("2023-06").is_err());
+    assert!(matches!(parse_value_literal("2023-06-15").unwrap().inner, ValueLiteral::Date(_)));
+}
+
+#[test]
+fn basic_dates_are_only_accepted_when_enabled() {
+    let options = ParserOptions { allow_basic_dates: true, ..Default::default() };
+    let basic = parse_value_literal_with_options("20230101", &options).unwrap();
+    assert_eq!(basic.value_type(), ValueType::Date);
+    assert_eq!(basic.to_string(), "2023-01-01");
+    assert_eq!(basic.original(), Some("20230101"));
+
+    assert!(!matches!(
+        parse_value_literal_with_options("2023011", &options).map(|literal| literal.inner),
+        Ok(ValueLiteral::Date(_))
+    ));
+    assert!(!matches!(
+        parse_value_literal_with_options("202301011", &options).map(|literal| literal.inner),
+        Ok(ValueLiteral::Date(_))
+    ));
+    assert!(matches!(parse_value_literal_with_options("20231301", &options).unwrap().inner, ValueLiteral::Integer(_)));
+
+    assert!(matches!(parse_value_literal("20230101").unwrap().inner, ValueLiteral::Integer(_)));
+}
+
+#[test]
+fn all_spans_cover_each_fragment_within_the_literal() {
+    let text = |input: &str, span: Span| input[span.begin.column as usize - 1..span.end.column as usize - 1].to_owned();
+    for (input, fragments) in [
+        ("2024-01-01", vec!["2024-01-01"]),
+        ("2024-01-01T10:00:30.5", vec!["2024-01-01", "10:00:30.5"]),
+        ("2024-01-01T10:00 Europe/London", vec!["2024-01-01", "10:00", "Europe/London"]),
+        ("2024-01-01T10:00+05:30", vec!["2024-01-01", "10:00", "+05:30"]),
+        ("2024-01-01T10:00Z", vec!["2024-01-01", "10:00", "Z"]),
+    ] {
+        let parsed = parse_value_literal(input).unwrap();
+        assert_full_span_coverage(&parsed, input);
+        let spans = parsed.all_spans();
+        assert_eq!(text(input, spans[0]), input);
+        assert_eq!(spans[1..].iter().map(|&span| text(input, span)).collect::<Vec<_>>(), fragments);
+    }
+    assert_eq!(parse_value_literal("5").unwrap().all_spans().len(), 1);
+}
+
+#[test]
+fn sub_nanosecond_digits_are_truncated_or_rounded() {
+    // the grammar accepts at most nine fraction digits, so finer fractions only come from constructed fragments
+    assert!(parse_value_literal("2024-01-01T00:00:00.1234567895").is_err());
+    let time = |fraction: &str| TimeFragment {
+        second_fraction: Some(fraction.to_owned()),
+        ..parse_datetime("2024-01-01T00:00:00").1.time
+    };
+
+    let precise = time("1234567895");
+    assert_eq!(precise.fraction_nanos(), Some(123_456_789));
+    assert_eq!(precise.fraction_nanos_with(FractionPolicy::Truncate), Some(123_456_789));
+    assert_eq!(precise.fraction_nanos_with(FractionPolicy::RoundHalfUp), Some(123_456_790));
+
+    assert_eq!(time("1234567894").fraction_nanos_with(FractionPolicy::RoundHalfUp), Some(123_456_789));
+    assert_eq!(time("5").fraction_nanos_with(FractionPolicy::RoundHalfUp), Some(500_000_000));
+    assert_eq!(time("9999999999").fraction_nanos_with(FractionPolicy::RoundHalfUp), Some(1_000_000_000));
+    let (_, whole_seconds) = parse_datetime("2024-01-01T00:00:00");
+    assert_eq!(whole_seconds.time.fraction_nanos_with(FractionPolicy::RoundHalfUp), None);
+}
+
+#[test]
+fn truncating_to_seconds_carries_rounding_into_the_date() {
+    let truncate = |literal: &str, policy| parse_datetime(literal).1.truncate_to_seconds(policy).to_string();
+    assert_eq!(truncate("2024-03-01T10:20:30.9", FractionPolicy::Truncate), "2024-03-01T10:20:30");
+    assert_eq!(truncate("2024-03-01T10:20:30.4", FractionPolicy::RoundHalfUp), "2024-03-01T10:20:30");
+    assert_eq!(truncate("2024-03-01T10:20:30.5", FractionPolicy::RoundHalfUp), "2024-03-01T10:20:31");
+    assert_eq!(truncate("2024-03-01T10:20", FractionPolicy::RoundHalfUp), "2024-03-01T10:20");
+    assert_eq!(truncate("2024-03-01T10:59:59.5", FractionPolicy::RoundHalfUp), "2024-03-01T11:00:00");
+    assert_eq!(truncate("2024-03-01T23:59:59.9", FractionPolicy::RoundHalfUp), "2024-03-02T00:00:00");
+    assert_eq!(truncate("2024-03-01T23:59:59.9", FractionPolicy::Truncate), "2024-03-01T23:59:59");
+    assert_eq!(truncate("2024-04-30T23:59:59.9", FractionPolicy::RoundHalfUp), "2024-05-01T00:00:00");
+    assert_eq!(truncate("2024-02-28T23:59:59.9", FractionPolicy::RoundHalfUp), "2024-02-29T00:00:00");
+    assert_eq!(truncate("2023-02-28T23:59:59.9", FractionPolicy::RoundHalfUp), "2023-03-01T00:00:00");
+    assert_eq!(truncate("2023-12-31T23:59:59.9", FractionPolicy::RoundHalfUp), "2024-01-01T00:00:00");
+    assert_eq!(truncate("9999-12-31T23:59:59.9", FractionPolicy::RoundHalfUp), "+10000-01-01T00:00:00");
+    assert_eq!(truncate("2016-12-31T23:59:60.5", FractionPolicy::RoundHalfUp), "2017-01-01T00:00:00");
+    assert_eq!(truncate("2016-12-31T23:59:60.4", FractionPolicy::RoundHalfUp), "2016-12-31T23:59:60");
+    assert_eq!(truncate("2016-12-31T23:59:60.9", FractionPolicy::Truncate), "2016-12-31T23:59:60");
+}
+
+#[test]
+fn spaced_date_separators_are_only_accepted_when_enabled() {
+    let options = ParserOptions { allow_spaced_date_separators: true, ..Default::default() };
+    for (input, unspaced) in [
+        ("2023 - 01 - 01", "2023-01-01"),
+        ("2023-01-01", "2023-01-01"),
+        ("2023- 01 -01T10 : 30:00", "2023-01-01T10:30:00"),
+        ("2023 - 01 - 01T10 : 30 Europe/London", "2023-01-01T10:30 Europe/London"),
+        ("2023-01-01T10:30+05 : 30", "2023-01-01T10:30+05:30"),
+    ] {
+        let parsed = parse_value_literal_with_options(input, &options).unwrap();
+        assert_full_span_coverage(&parsed, input);
+        assert_eq!(parsed.to_string(), unspaced);
+        assert_eq!(parsed.original(), (input != unspaced).then_some(input));
+        assert!(parse_value_literal(input).is_ok() == (input == unspaced), "{input}");
+    }
+    for input in ["2023 - 01", "2023 - 01 - 01T10:30Europe/London", "2023 01 01", "2023-01-01 T10:30"] {
+        assert!(parse_value_literal_with_options(input, &options).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn date_parts_are_range_checked() {
+    let date = |date: &str| date.parse::<DateFragment>().unwrap();
+    assert_eq!(Year::new(2024).unwrap().get(), 2024);
+    assert_eq!(Month::new(12).unwrap().get(), 12);
+    assert_eq!(Day::new(31).unwrap().get(), 31);
+    let out_of_range =
+        |component, value, range| TypeQLError::ComponentOutOfRange { component, value, range, span: None };
+    assert_eq!(Year::new(10000), Err(out_of_range("year", 10000, 0..=9999).into()));
+    assert_eq!(Month::new(0), Err(out_of_range("month", 0, 1..=12).into()));
+    assert_eq!(Month::new(13), Err(out_of_range("month", 13, 1..=12).into()));
+    assert_eq!(Day::new(0), Err(out_of_range("day", 0, 1..=31).into()));
+    assert_eq!(Day::new(32), Err(out_of_range("day", 32, 1..=31).into()));
+
+    let parts = date("2024-02-29").parts().unwrap();
+    assert_eq!(parts, (Year::new(2024).unwrap(), Month::new(2).unwrap(), Day::new(29).unwrap()));
+    assert_eq!(date("+12345-01-01").parts(), Err(out_of_range("year", 12345, 0..=9999).into()));
+    assert_eq!(date("-0001-01-01").parts(), Err(out_of_range("year", -1, 0..=9999).into()));
+    assert_eq!(date("2024-01-32").parts(), Err(out_of_range("day", 32, 1..=31).into()));
+    assert_eq!(date("2023-02-29").parts(), Err(out_of_range("day", 29, 1..=28).into()));
+}
+
+#[test]
+fn week_dates_are_expanded_when_allowed() {
+    let options = ParserOptions { allow_week_dates: true, ..Default::default() };
+    for (week_date, calendar_date) in [
+        ("2023-W01-1", "2023-01-02"),
+        ("2023-W05-1", "2023-01-30"),
+        ("2020-W01-1", "2019-12-30"),
+        ("2020-W53-7", "2021-01-03"),
+    ] {
+        let parsed = parse_value_literal_with_options(week_date, &options).unwrap();
+        assert_full_span_coverage(&parsed, week_date);
+        assert_eq!(parsed.to_string(), calendar_date);
+        assert_eq!(parsed.original(), Some(week_date));
+    }
+
+    for invalid in ["2023-W53-1", "2023-W00-1", "2023-W54-1", "2023-W01-0", "2023-W01-8"] {
+        let error = parse_value_literal_with_options(invalid, &options).unwrap_err();
+        assert_eq!(error, TypeQLError::InvalidWeekDate { literal: invalid.to_owned() }.into());
+    }
+    assert!(parse_value_literal_with_options("2023-W1-1", &options).is_err());
+    assert!(parse_value_literal("2023-W01-1").is_err());
+}
+
+#[test]
+fn ordinal_dates_round_trip_when_allowed() {
+    let options = ParserOptions { allow_ordinal_dates: true, ..Default::default() };
+    for (ordinal_date, calendar_date) in [
+        ("2023-001", "2023-01-01"),
+        ("2023-032", "2023-02-01"),
+        ("2023-365", "2023-12-31"),
+        ("2024-060", "2024-02-29"),
+        ("2024-366", "2024-12-31"),
+    ] {
+        let parsed = parse_value_literal_with_options(ordinal_date, &options).unwrap();
+        assert_full_span_coverage(&parsed, ordinal_date);
+        assert_eq!(parsed.to_string(), calendar_date);
+        assert_eq!(parsed.original(), Some(ordinal_date));
+        let ValueLiteral::Date(date) = parsed.inner.clone() else { panic!("expected a date: {parsed:?}") };
+        assert_eq!(date.date.to_ordinal_string(), Ok(ordinal_date.to_owned()));
+    }
+
+    for invalid in ["2023-000", "2023-366", "2024-367"] {
+        let error = parse_value_literal_with_options(invalid, &options).unwrap_err();
+        assert_eq!(error, TypeQLError::InvalidOrdinalDate { literal: invalid.to_owned() }.into());
+    }
+    assert!(parse_value_literal_with_options("2023-32", &options).is_err());
+    assert!(parse_value_literal("2023-032").is_err());
+}
+
+#[test]
+fn slash_dates_are_read_in_the_configured_order() {
+    for (order, expected) in [(DateOrder::MDY, "2023-01-02"), (DateOrder::DMY, "2023-02-01")] {
+        let options = ParserOptions { slash_date_order: Some(order), ..Default::default() };
+        let parsed = parse_value_literal_with_options("01/02/2023", &options).unwrap();
+        assert_full_span_coverage(&parsed, "01/02/2023");
+        assert_eq!(parsed.to_string(), expected);
+        assert_eq!(parsed.original(), Some("01/02/2023"));
+    }
+    let options = ParserOptions { slash_date_order: Some(DateOrder::YMD), ..Default::default() };
+    assert_eq!(parse_value_literal_with_options("2023/1/2", &options).unwrap().to_string(), "2023-01-02");
+
+    let options = ParserOptions { slash_date_order: Some(DateOrder::MDY), ..Default::default() };
+    for invalid in ["13/01/2023", "02/29/2023", "01/02/23", "2023/01/02"] {
+        let error = parse_value_literal_with_options(invalid, &options).unwrap_err();
+        assert_eq!(error, TypeQLError::InvalidDate { date: invalid.to_owned() }.into());
+    }
+    assert!(parse_value_literal("01/02/2023").is_err());
+}
+
+#[test]
+fn epoch_seconds_convert_to_utc_datetimes_when_allowed() {
+    let options = ParserOptions { allow_epoch_seconds: true, ..Default::default() };
+    for (epoch, expected) in [
+        ("@0", "1970-01-01T00:00:00Z"),
+        ("epoch(0)", "1970-01-01T00:00:00Z"),
+        ("@1700000000", "2023-11-14T22:13:20Z"),
+        ("epoch(-86401)", "1969-12-30T23:59:59Z"),
+    ] {
+        let parsed = parse_value_literal_with_options(epoch, &options).unwrap();
+        assert_full_span_coverage(&parsed, epoch);
+        assert_eq!(parsed.to_string(), expected);
+        assert_eq!(parsed.original(), Some(epoch));
+        assert_eq!(parsed.as_datetime_tz().map(|date_time| &date_time.timezone), Some(&TimeZone::Utc));
+    }
+    let error = parse_value_literal_with_options("@99999999999999999", &options).unwrap_err();
+    assert_eq!(error, TypeQLError::EpochOutOfRange { seconds: "99999999999999999".to_owned() }.into());
+    assert!(parse_value_literal("@0").is_err());
+}
+
+#[test]
+fn dates_and_times_order_chronologically() {
+    let mut dates: Vec<DateFragment> =
+        ["2024-01-01", "2023-12-31", "2024-02-01", "2024-01-31", "0999-06-15"].map(|date| date.parse().unwrap()).into();
+    dates.sort();
+    let sorted = dates.iter().map(DateFragment::to_string).collect::<Vec<_>>();
+    assert_eq!(sorted, ["0999-06-15", "2023-12-31", "2024-01-01", "2024-01-31", "2024-02-01"]);
+
+    let time = |time: &str| time.parse::<TimeFragment>().unwrap();
+    assert!(time("09:59:59.999") < time("10:00"));
+    assert!(time("10:00") < time("10:00:00"), "equal times are ordered by their text");
+    assert_eq!(time("10:00:00").cmp(&time("10:00:00")), std::cmp::Ordering::Equal);
+
+    let date_time = |date_time: &str| date_time.parse::<DateTimeLiteral>().unwrap();
+    let keyed: std::collections::BTreeMap<_, _> =
+        [(date_time("2024-01-01T00:00"), 2), (date_time("2023-12-31T23:59:59"), 1)].into();
+    assert_eq!(keyed.into_values().collect::<Vec<_>>(), [1, 2]);
+
+    let zoned = |date_time: &str| date_time.parse::<DateTimeTZLiteral>().unwrap();
+    let (earlier_instant, later_instant) = (zoned("2024-01-01T10:00+02:00"), zoned("2024-01-01T09:00Z"));
+    assert!(earlier_instant > later_instant, "zone-naive ordering compares local times");
+    #[cfg(feature = "chrono-tz")]
+    {
+        assert_eq!(earlier_instant.cmp_instant(&later_instant), Ok(std::cmp::Ordering::Less));
+        let skipped = zoned("2024-03-31T01:30 Europe/London");
+        assert!(skipped.cmp_instant(&later_instant).is_err(), "a skipped local time has no instant");
+    }
+}
+
+#[test]
+fn datetimes_are_assembled_from_parsed_parts() {
+    let date: DateLiteral = "2024-03-10".parse().unwrap();
+    let time: TimeFragment = "02:30:15.25".parse().unwrap();
+    let date_time = date.with_time(time);
+    assert_eq!(date_time, "2024-03-10T02:30:15.25".parse().unwrap());
+
+    let zoned = date_time.clone().with_timezone(TimeZone::IANA("America/New_York".to_owned()));
+    assert_eq!(zoned, "2024-03-10T02:30:15.25 America/New_York".parse().unwrap());
+    assert_eq!(date_time.with_timezone(TimeZone::Utc).to_string(), "2024-03-10T02:30:15.25Z");
+}
+
+#[test]
+fn datetimes_split_into_parts_that_recombine() {
+    let date_time: DateTimeLiteral = "2024-03-10T02:30:15.25".parse().unwrap();
+    let (date, time) = date_time.clone().split();
+    assert_eq!((date.to_string().as_str(), time.to_string().as_str()), ("2024-03-10", "02:30:15.25"));
+    assert_eq!(date.with_time(time), date_time);
+
+    let zoned: DateTimeTZLiteral = "2024-03-10T02:30 America/New_York".parse().unwrap();
+    let (date, time, timezone) = zoned.clone().split();
+    assert_eq!(timezone, TimeZone::IANA("America/New_York".to_owned()));
+    assert_eq!(date.with_time(time).with_timezone(timezone), zoned);
+}
+
+#[test]
+fn naive_datetime_assumes_a_default_timezone() {
+    let (parsed, naive) = parse_datetime("2024-06-01T09:30");
+    let zoned = naive.assume_timezone(TimeZone::IANA("Europe/Berlin".to_owned()));
+    assert_eq!(zoned.to_string(), "2024-06-01T09:30 Europe/Berlin");
+    assert_eq!((zoned.date, zoned.time), (naive.date, naive.time));
+
+    let span = parsed.span();
+    let assume_utc = |value| match value {
+        ValueLiteral::DateTime(naive) => ValueLiteral::DateTimeTz(naive.assume_timezone(TimeZone::Utc)),
+        other => other,
+    };
+    let zoned = parsed.map_value(assume_utc);
+    assert_eq!(zoned.span(), span);
+    assert_eq!(zoned.to_string(), "2024-06-01T09:30Z");
+}
+
+#[test]
+fn two_digit_years_expand_around_the_pivot() {
+    let date =
+        |year: &str| DateFragment { year: year.to_owned(), month: Some("06".to_owned()), day: Some("15".to_owned()) };
+    assert_eq!(date("29").expand_year(30), date("2029"));
+    assert_eq!(date("30").expand_year(30), date("1930"));
+    assert_eq!(date("00").expand_year(30), date("2000"));
+    assert_eq!(date("99").expand_year(30), date("1999"));
+    assert_eq!(date("05").expand_year(0), date("1905"));
+    assert_eq!(date("2029").expand_year(30), date("2029"));
+    assert_eq!(date("+29").expand_year(30), date("+29"));
+}
+
+#[test]
+fn relative_keywords_resolve_against_the_given_now() {
+    let now = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().and_hms_milli_opt(13, 5, 9, 250).unwrap();
+    let options = ParserOptions { relative_datetime_now: Some(now), ..Default::default() };
+    for (keyword, resolved) in [
+        ("now", "2024-02-29T13:05:09.25"),
+        ("today", "2024-02-29"),
+        ("yesterday", "2024-02-28"),
+        ("tomorrow", "2024-03-01"),
+    ] {
+        let parsed = parse_value_literal_with_options(keyword, &options).unwrap();
+        assert_full_span_coverage(&parsed, keyword);
+        assert_eq!(parsed.to_string(), resolved);
+        assert_eq!(parsed.original(), Some(keyword));
+    }
+    let midnight = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let options_at_midnight = ParserOptions { relative_datetime_now: Some(midnight), ..Default::default() };
+    assert_eq!(
+        parse_value_literal_with_options("now", &options_at_midnight).unwrap().to_string(),
+        "2024-01-01T00:00:00"
+    );
+
+    assert!(parse_value_literal_with_options("Now", &options).is_err());
+    assert!(parse_value_literal_with_options("today", &ParserOptions::default()).is_err());
+    assert!(parse_value_literal("now").is_err());
+}
+
+#[test]
+fn duplicate_timezones_are_reported_clearly() {
+    let options = ParserOptions { allow_week_dates: true, ..Default::default() };
+    for (literal, second_zone) in [
+        ("2024-01-01T10:00Z+05:00", (18, 24)),
+        ("2024-01-01T10:00+01:00 Europe/London", (24, 37)),
+        ("2024-01-01T10:00 Europe/London Europe/Paris", (32, 44)),
+    ] {
+        for error in [
+            parse_value_literal(literal).unwrap_err(),
+            parse_value_literal_with_options(literal, &options).unwrap_err(),
+        ] {
+            let span = Span {
+                begin: LineColumn { line: 1, column: second_zone.0 },
+                end: LineColumn { line: 1, column: second_zone.1 },
+            };
+            assert_eq!(error, TypeQLError::DuplicateTimeZone { literal: literal.to_owned(), span: Some(span) }.into());
+        }
+    }
+    let error = parse_value_literal("2024-01-01T10:00Z Z").unwrap_err();
+    assert!(error.to_string().contains("[TQL03]"), "{error}");
+}
+
+#[test]
+fn datetime_tz_is_built_from_validated_components() {
+    let utc = || TimeZone::Utc;
+    let built = DateTimeTZLiteral::try_new(2024, 2, 29, 9, 5, None, None, utc()).unwrap();
+    assert_eq!(built.to_string(), "2024-02-29T09:05Z");
+    let built =
+        DateTimeTZLiteral::try_new(12, 1, 2, 23, 59, Some(7), Some(250_000_000), TimeZone::ISO("+05:30".to_owned()));
+    assert_eq!(built.unwrap().to_string(), "0012-01-02T23:59:07.25+05:30");
+    let built = DateTimeTZLiteral::try_new(2024, 1, 1, 0, 0, None, Some(1), TimeZone::IANA("Europe/London".to_owned()));
+    assert_eq!(built.unwrap().to_string(), "2024-01-01T00:00:00.000000001 Europe/London");
+    let built = DateTimeTZLiteral::try_new(2024, 1, 1, 0, 0, Some(0), Some(0), utc()).unwrap();
+    assert_eq!(built.to_string().parse(), Ok(built));
+    let built = DateTimeTZLiteral::try_new(2024, 2, 29, 9, 5, None, None, TimeZone::ISO("Z".to_owned())).unwrap();
+    assert_eq!(built.timezone, TimeZone::Utc);
+    assert_eq!(built, DateTimeTZLiteral::try_new(2024, 2, 29, 9, 5, None, None, utc()).unwrap());
+
+    let component_out_of_range =
+        |component, value, range| Err(TypeQLError::ComponentOutOfRange { component, value, range, span: None }.into());
+    assert_eq!(
+        DateTimeTZLiteral::try_new(1_000_000, 1, 1, 0, 0, None, None, utc()),
+        component_out_of_range("year", 1_000_000, -262_143..=262_142)
+    );
+    assert_eq!(
+        DateTimeTZLiteral::try_new(2024, 13, 1, 0, 0, None, None, utc()),
+        component_out_of_range("month", 13, 1..=12)
+    );
+    assert_eq!(
+        DateTimeTZLiteral::try_new(2023, 2, 29, 0, 0, None, None, utc()),
+        component_out_of_range("day", 29, 1..=28)
+    );
+    assert_eq!(
+        DateTimeTZLiteral::try_new(2024, 1, 0, 0, 0, None, None, utc()),
+        component_out_of_range("day", 0, 1..=31)
+    );
+    assert_eq!(
+        DateTimeTZLiteral::try_new(2024, 1, 1, 24, 0, None, None, utc()),
+        component_out_of_range("hour", 24, 0..=23)
+    );
+    assert_eq!(
+        DateTimeTZLiteral::try_new(2024, 1, 1, 0, 60, None, None, utc()),
+        component_out_of_range("minute", 60, 0..=59)
+    );
+    assert_eq!(
+        DateTimeTZLiteral::try_new(2024, 1, 1, 0, 0, Some(60), None, utc()),
+        component_out_of_range("second", 60, 0..=59)
+    );
+    assert_eq!(
+        DateTimeTZLiteral::try_new(2024, 1, 1, 0, 0, Some(0), Some(1_000_000_000), utc()),
+        component_out_of_range("nanosecond", 1_000_000_000, 0..=999_999_999)
+    );
+
+    for offset in ["+5:30", "05:30", "+05:30:00", "+29:00"] {
+        assert_eq!(
+            DateTimeTZLiteral::try_new(2024, 1, 1, 0, 0, None, None, TimeZone::ISO(offset.to_owned())),
+            Err(TypeQLError::InvalidTimeZoneOffset { offset: offset.to_owned() }.into())
+        );
+    }
+    assert_eq!(
+        DateTimeTZLiteral::try_new(2024, 1, 1, 0, 0, None, None, TimeZone::IANA("europe london".to_owned())),
+        Err(TypeQLError::UnknownTimeZone { name: "europe london".to_owned() }.into())
+    );
+}
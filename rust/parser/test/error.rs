@@ -4,8 +4,15 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use itertools::Itertools;
+
 use super::assert_valid_eq_repr;
-use crate::parse_query;
+use crate::{
+    common::{error::TypeQLError, LineColumn, Span},
+    iter_value_literals_spanned_with_options, parse_query, parse_query_with_options,
+    parse_value_literal_partial_with_options, parse_value_literal_with_options, parse_value_literals_with_options,
+    parser::ParserOptions,
+};
 
 #[test]
 fn test_parsing_empty_string() {
@@ -29,6 +36,100 @@ fn test_missing_comma() {
     assert!(parse_query("match\n($x $y) isa has-cast;").is_err());
 }
 
+#[test]
+fn oversized_query_exceeds_node_limit() {
+    let statements = (0..200).map(|i| format!("$x{i} isa person, has name \"{i}\";")).join("\n");
+    let query = format!("match\n{statements}");
+
+    let options = ParserOptions { max_nodes: Some(1000), ..Default::default() };
+    assert_eq!(
+        parse_query_with_options(&query, &options).err(),
+        Some(TypeQLError::InputTooComplex { limit: 1000 }.into())
+    );
+
+    assert!(parse_query_with_options(&query, &ParserOptions::default()).is_ok());
+    let options = ParserOptions { max_nodes: Some(100), ..Default::default() };
+    assert!(parse_query_with_options("match $x isa person;", &options).is_ok());
+}
+
+#[test]
+fn node_limit_counts_the_root_and_applies_to_every_literal_form() {
+    // eof_value_literal, value_literal, signed_integer, integer_literal and EOI
+    let too_complex = Err(TypeQLError::InputTooComplex { limit: 4 }.into());
+    let options = ParserOptions { max_nodes: Some(4), ..Default::default() };
+    assert_eq!(parse_value_literal_with_options("5", &options), too_complex);
+    let options = ParserOptions { max_nodes: Some(5), ..Default::default() };
+    assert!(parse_value_literal_with_options("5", &options).is_ok());
+
+    let options = ParserOptions {
+        max_nodes: Some(1),
+        allow_currency_and_percent: true,
+        allow_partial_dates: true,
+        allow_basic_dates: true,
+        allow_spaced_date_separators: true,
+        ..Default::default()
+    };
+    for literal in ["$1,234.50", "2023", "20230101", "2023 - 01 - 01"] {
+        let error = parse_value_literal_with_options(literal, &options).unwrap_err();
+        assert_eq!(error, TypeQLError::InputTooComplex { limit: 1 }.into(), "{literal}");
+    }
+}
+
+#[test]
+fn input_longer_than_limit_is_rejected_before_parsing() {
+    let options = ParserOptions { max_input_len: Some(10), ..Default::default() };
+    let too_long = |len| Err(TypeQLError::InputTooLong { len, max: 10 }.into());
+    assert!(parse_value_literal_with_options("2024-01-01", &options).is_ok());
+    assert_eq!(parse_value_literal_with_options("2024-01-01T10:00", &options), too_long(16));
+    // rejected by length alone, although the input is not even a literal
+    assert_eq!(parse_value_literal_with_options("(((((((((((", &options), too_long(11));
+    assert_eq!(parse_query_with_options("match $x isa person;", &options).err(), too_long(20).err());
+    assert_eq!(parse_value_literal_partial_with_options("1, 2, 3, 4, 5", &options).err(), too_long(13).err());
+
+    assert_eq!(parse_value_literals_with_options("1 2 3 4 5 6", &options).err(), too_long(11).err());
+    let mut literals = iter_value_literals_spanned_with_options("1 2 3 4 5 6", &options);
+    assert_eq!(literals.next().map(Result::err), Some(too_long(11).err()));
+    assert!(literals.next().is_none());
+    assert_eq!(parse_value_literals_with_options("1 2 3 4 5", &options).unwrap().len(), 5);
+}
+
+#[test]
+fn string_literal_longer_than_limit_is_rejected() {
+    let options = ParserOptions { max_string_len: Some(5), ..Default::default() };
+    assert!(parse_value_literal_with_options(r#""abc""#, &options).is_ok());
+    let span = Span { begin: LineColumn { line: 1, column: 1 }, end: LineColumn { line: 1, column: 7 } };
+    assert_eq!(
+        parse_value_literal_with_options(r#""abcd""#, &options),
+        Err(TypeQLError::StringLiteralTooLong { len: 6, max: 5, span: Some(span) }.into())
+    );
+    assert!(parse_value_literal_with_options(r#""ab""#, &options).is_ok());
+
+    let query = "match\n$x has name \"abcd\";";
+    let span = Span { begin: LineColumn { line: 2, column: 13 }, end: LineColumn { line: 2, column: 19 } };
+    assert_eq!(
+        parse_query_with_options(query, &options).err(),
+        Some(TypeQLError::StringLiteralTooLong { len: 6, max: 5, span: Some(span) }.into())
+    );
+    assert!(parse_query_with_options(query, &ParserOptions::default()).is_ok());
+}
+
+#[test]
+fn string_limit_applies_to_every_literal_entry_point() {
+    let options = ParserOptions { max_string_len: Some(5), allow_casts: true, ..Default::default() };
+    let too_long = |begin, end| {
+        let span = Span { begin: LineColumn { line: 1, column: begin }, end: LineColumn { line: 1, column: end } };
+        TypeQLError::StringLiteralTooLong { len: 6, max: 5, span: Some(span) }
+    };
+    assert!(parse_value_literal_with_options(r#""12"::integer"#, &options).is_ok());
+    assert_eq!(parse_value_literal_with_options(r#""1234"::integer"#, &options), Err(too_long(1, 7).into()));
+
+    assert_eq!(parse_value_literal_partial_with_options(r#""abcd" rest"#, &options), Err(too_long(1, 7).into()));
+    assert_eq!(parse_value_literals_with_options(r#"1 "abcd" 2"#, &options), Err(vec![too_long(3, 9)].into()));
+    let results = iter_value_literals_spanned_with_options(r#"1 "abcd" 2"#, &options).collect::<Vec<_>>();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[1], Err(too_long(3, 9).into()));
+}
+
 #[test]
 fn test_limit_mistake() {
     let parsed = parse_query("match\n($x, $y); limit1;");
@@ -0,0 +1,1386 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cmp::Ordering;
+
+use pest::Parser;
+
+use super::assert_full_span_coverage;
+use crate::{
+    common::{error::TypeQLError, LineColumn, Span, Spanned},
+    iter_value_literals_spanned, iter_value_literals_spanned_with_options, normalize_literals, parse_query,
+    parse_query_with_options, parse_value_literal, parse_value_literal_bytes, parse_value_literal_partial,
+    parse_value_literal_partial_with_options, parse_value_literal_with_options, parse_value_literals,
+    parse_value_literals_with_options, parse_value_literals_with_sink,
+    parser::{rule_to_value_type, ParserOptions, Rule, TypeQLParser, Version, GRAMMAR_VERSION},
+    value::{
+        collect_dates, collect_decimals, collect_integers, DateFragment, DateLiteral, DurationLiteral, EscapePolicy,
+        IntegerLiteral, Sign, SignedDecimalLiteral, SignedIntegerLiteral, SqlDialect, StringLiteral, TimeFragment,
+        TimeZone, ValidationOptions, ValueLiteral, ValueType,
+    },
+    Literal,
+};
+
+fn parse_all(literals: &[&str]) -> Vec<Literal> {
+    literals
+        .iter()
+        .map(|literal| {
+            let parsed = parse_value_literal(literal).unwrap();
+            assert_full_span_coverage(&parsed, literal);
+            parsed
+        })
+        .collect()
+}
+
+fn decimal(sign: Option<Sign>, decimal: &str) -> ValueLiteral {
+    ValueLiteral::Decimal(SignedDecimalLiteral { sign, decimal: decimal.to_owned() })
+}
+
+#[test]
+fn percent_decimal_is_divided_by_one_hundred_when_allowed() {
+    let options = ParserOptions { allow_currency_and_percent: true, ..Default::default() };
+    let parsed = parse_value_literal_with_options("12%", &options).unwrap();
+    assert_full_span_coverage(&parsed, "12%");
+    assert_eq!(parsed.inner, decimal(None, "0.12"));
+    assert_eq!(parsed.original(), Some("12%"));
+
+    assert_eq!(parse_value_literal_with_options("150%", &options).unwrap().inner, decimal(None, "1.50"));
+    assert_eq!(parse_value_literal_with_options("-0.5%", &options).unwrap().inner, decimal(Some(Sign::Minus), "0.005"));
+}
+
+#[test]
+fn currency_decimal_is_stripped_when_allowed() {
+    let options = ParserOptions { allow_currency_and_percent: true, ..Default::default() };
+    let parsed = parse_value_literal_with_options("$1,234.50", &options).unwrap();
+    assert_full_span_coverage(&parsed, "$1,234.50");
+    assert_eq!(parsed.inner, decimal(None, "1234.50"));
+    assert_eq!(parsed.original(), Some("$1,234.50"));
+
+    assert_eq!(parse_value_literal_with_options("-€5", &options).unwrap().inner, decimal(Some(Sign::Minus), "5.0"));
+    assert!(parse_value_literal_with_options("$1,23.50", &options).is_err());
+}
+
+#[test]
+fn currency_and_percent_decimals_are_rejected_by_default() {
+    assert!(parse_value_literal_with_options("12%", &ParserOptions::default()).is_err());
+    assert!(parse_value_literal_with_options("$1,234.50", &ParserOptions::default()).is_err());
+    assert!(parse_value_literal("12%").is_err());
+}
+
+#[test]
+fn canonical_literals_have_no_original() {
+    let options = ParserOptions { allow_currency_and_percent: true, ..Default::default() };
+    let parsed = parse_value_literal_with_options("0.12", &options).unwrap();
+    assert_eq!(parsed.inner, decimal(None, "0.12"));
+    assert_eq!(parsed.original(), None);
+}
+
+#[test]
+fn literals_of_every_kind_sort_by_value_type_then_value() {
+    let expected = parse_all(&[
+        "false",
+        "true",
+        "-3",
+        "2",
+        "10",
+        "-1.5",
+        "0.25",
+        "1.5e1",
+        "'a'",
+        r#""b""#,
+        "2023-06-01",
+        "2024-01-01",
+        "2023-12-31T23:59",
+        "2024-01-01T00:00",
+        "2024-01-01T00:00:00 Europe/London",
+        "2024-01-01T00:00:00Z",
+        "P1D",
+    ]);
+    let mut literals = expected.clone();
+    literals.reverse();
+    literals.sort_by(Literal::total_cmp);
+    assert_eq!(literals, expected);
+
+    let value_types = literals.iter().map(Literal::value_type).collect::<Vec<_>>();
+    assert!(value_types.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(ValueType::Boolean < ValueType::Integer && ValueType::String < ValueType::Date);
+}
+
+#[test]
+fn order_preserving_keys_sort_as_total_cmp() {
+    let literals = parse_all(&[
+        "false",
+        "true",
+        "-10",
+        "-2",
+        "-0",
+        "007",
+        "12",
+        "-1.5e3",
+        "-0.125",
+        "-0.12",
+        "0.0",
+        "0.0012",
+        "1.5",
+        "1.50",
+        "1.5e300",
+        "''",
+        "'a'",
+        r#""a\u0000""#,
+        r#""b""#,
+        "-0001-01-01",
+        "2023-06-01",
+        "2023-12-31T23:59",
+        "2024-01-01T00:00:00.5",
+        "2024-01-01T00:00:00 Europe/London",
+        "2024-01-01T00:00:00Z",
+        "+05:00",
+        "P1D",
+        "P1W",
+        "1..5",
+        "1..<5",
+        "1..10",
+        "2..3",
+    ]);
+    for lhs in &literals {
+        for rhs in &literals {
+            let key_order = lhs.order_preserving_key().cmp(&rhs.order_preserving_key());
+            assert_eq!(key_order, lhs.total_cmp(rhs), "{lhs} and {rhs}");
+        }
+    }
+}
+
+#[test]
+fn negation_flips_the_sign_except_of_zero() {
+    let negated = |literal: &str| match parse_value_literal(literal).unwrap().inner {
+        ValueLiteral::Integer(integer) => integer.negated().to_string(),
+        ValueLiteral::Decimal(decimal) => decimal.negated().to_string(),
+        other => panic!("expected a number: {other}"),
+    };
+    assert_eq!(negated("5"), "-5");
+    assert_eq!(negated("+5"), "-5");
+    assert_eq!(negated("-5"), "5");
+    assert_eq!(negated("0"), "0");
+    assert_eq!(negated("-00"), "00");
+    assert_eq!(negated("1.5e3"), "-1.5e3");
+    assert_eq!(negated("-0.25"), "0.25");
+    assert_eq!(negated("-0.0"), "0.0");
+}
+
+#[test]
+fn textual_decimal_comparison() {
+    let decimal = |text: &str| match text.strip_prefix('-') {
+        Some(magnitude) => SignedDecimalLiteral { sign: Some(Sign::Minus), decimal: magnitude.to_owned() },
+        None => SignedDecimalLiteral { sign: None, decimal: text.to_owned() },
+    };
+    let cmp = |lhs: &str, rhs: &str| decimal(lhs).cmp_textual(&decimal(rhs));
+    assert_eq!(cmp("1.5", "1.50"), Ordering::Equal);
+    assert_eq!(cmp("2", "10"), Ordering::Less);
+    assert_eq!(cmp("002.0", "2"), Ordering::Equal);
+    assert_eq!(cmp("0.09", "0.1"), Ordering::Less);
+    assert_eq!(cmp("-2.5", "-10.0"), Ordering::Greater);
+    assert_eq!(cmp("-0.0", "0.0"), Ordering::Equal);
+    assert_eq!(cmp("-0.1", "0.0"), Ordering::Less);
+}
+
+#[test]
+fn value_types_are_classified_as_temporal_or_numeric() {
+    use ValueType::*;
+    for (value_type, is_temporal, is_numeric) in [
+        (Null, false, false),
+        (Boolean, false, false),
+        (Integer, false, true),
+        (Decimal, false, true),
+        (String, false, false),
+        (Date, true, false),
+        (DateTime, true, false),
+        (DateTimeTz, true, false),
+        (TimeZone, false, false),
+        (Duration, true, false),
+        (Struct, false, false),
+    ] {
+        assert_eq!((value_type.is_temporal(), value_type.is_numeric()), (is_temporal, is_numeric), "{value_type:?}");
+    }
+    let [date, decimal, string] = &parse_all(&["2024-01-01", "1.5", "'2024-01-01'"])[..] else { unreachable!() };
+    assert!(date.is_temporal() && !date.is_numeric());
+    assert!(decimal.is_numeric() && !decimal.is_temporal());
+    assert!(!string.is_temporal() && !string.is_numeric());
+}
+
+#[test]
+fn numbers_compare_by_value_regardless_of_notation() {
+    let cmp = |lhs: &str, rhs: &str| parse_value_literal(lhs).unwrap().total_cmp(&parse_value_literal(rhs).unwrap());
+    assert_eq!(cmp("1.50", "1.5"), Ordering::Equal);
+    assert_eq!(cmp("15.0e-1", "1.5"), Ordering::Equal);
+    assert_eq!(cmp("-0", "+0"), Ordering::Equal);
+    assert_eq!(cmp("007", "7"), Ordering::Equal);
+    assert_eq!(cmp("2", "10"), Ordering::Less);
+    assert_eq!(cmp("-2", "-10"), Ordering::Greater);
+    assert_eq!(cmp("0.0012", "0.012"), Ordering::Less);
+}
+
+#[test]
+fn numbers_with_exponents_beyond_an_i64_still_order() {
+    let literal = |literal: &str| parse_value_literal(literal).unwrap();
+    let cmp = |lhs: &str, rhs: &str| literal(lhs).total_cmp(&literal(rhs));
+    assert_eq!(cmp("1.0e9223372036854775807", "2.0"), Ordering::Greater);
+    assert_eq!(cmp("1.0e99999999999999999999", "1.0e5"), Ordering::Greater);
+    assert_eq!(cmp("-1.0e99999999999999999999", "-1.0e5"), Ordering::Less);
+    assert_eq!(cmp("1.0e-99999999999999999999", "1.0e-5"), Ordering::Less);
+    assert_eq!(cmp("1.0e-99999999999999999999", "0.0"), Ordering::Greater);
+    for (lhs, rhs) in [("1.0e99999999999999999999", "1.0e5"), ("1.0e-99999999999999999999", "0.0")] {
+        assert!(literal(lhs).order_preserving_key() > literal(rhs).order_preserving_key(), "{lhs} and {rhs}");
+    }
+    assert_eq!(literal("1.0e99999999999999999999").to_canonical_string(), "1.0e99999999999999999999");
+    assert_eq!(literal("1.0e99999999999999999999").as_decimal().unwrap().as_f64_lossy(), (f64::INFINITY, false));
+}
+
+#[test]
+fn block_string_preserves_inner_quotes_and_newlines() {
+    let input = r#""""{
+  "name": "typeql",
+  "escape": "\n"
+}""""#;
+    let parsed = parse_value_literal(input).unwrap();
+    assert_full_span_coverage(&parsed, input);
+    let ValueLiteral::String(string) = &parsed.inner else { panic!("expected a string: {parsed:?}") };
+    assert!(string.is_block());
+    assert_eq!(string.unescape().unwrap(), "{\n  \"name\": \"typeql\",\n  \"escape\": \"\\n\"\n}");
+    assert_eq!(parsed.to_string(), input);
+}
+
+#[test]
+fn block_string_in_query_is_reproduced() {
+    let query = r#"insert
+$_ isa document,
+    has body """He said "hi" and left.""";"#;
+    let parsed = parse_query(query).unwrap();
+    assert_eq!(format!("{parsed:#}"), query);
+}
+
+#[test]
+fn standalone_literals_do_not_change_how_queries_parse() {
+    // a bare time zone is only a literal on its own, so in a query it is not read as a value
+    for query in ["match $x has zone Europe/London;", "match $x has zone Z;", "match $z = $x / Europe/London;"] {
+        assert!(parse_query(query).is_err(), "{query}");
+    }
+    assert!(parse_query("match $x has zone +01:00;").is_err());
+    for query in [
+        r#"match $x has zone "Europe/London";"#,
+        "match $x has Z $z;",
+        "match $res = $a / $b / 2;",
+        "match $res = 10 / $b;",
+        r#"match $x has name "", has nickname """block""";"#,
+    ] {
+        assert!(parse_query(query).is_ok(), "{query}");
+    }
+    assert_eq!(parse_value_literal("Europe/London").unwrap().value_type(), ValueType::TimeZone);
+}
+
+#[test]
+fn literals_in_a_query_are_normalized_in_place() {
+    let query = r#"match
+$x isa movie,
+    has release-date 2023-06-01T09:30,  # a comment 2023-06-01T09:30
+    has premiere 2023-06-01T09:30:00.500+0100,
+    has score +007.50, has title "Up";
+$y has release-date 1999-12-31T23:59:59.000;
+"#;
+    let expected = r#"match
+$x isa movie,
+    has release-date 2023-06-01T09:30:00,  # a comment 2023-06-01T09:30
+    has premiere 2023-06-01T09:30:00.5+01:00,
+    has score 7.5, has title "Up";
+$y has release-date 1999-12-31T23:59:59;
+"#;
+    assert_eq!(normalize_literals(query).unwrap(), expected);
+    assert_eq!(normalize_literals(expected).unwrap(), expected);
+    assert!(normalize_literals("match $x has").is_err());
+}
+
+#[test]
+fn heap_size_grows_with_the_literal_text() {
+    let long_string = format!("\"{}\"", "x".repeat(1000));
+    let [long_string, integer] = &parse_all(&[&long_string, "5"])[..] else { unreachable!() };
+    assert!(long_string.heap_size() >= 1002);
+    assert!(integer.heap_size() < long_string.heap_size());
+    assert_eq!(integer.clone().map_value(|_| ValueLiteral::TimeZone(TimeZone::Utc)).heap_size(), 0);
+
+    let options = ParserOptions { allow_currency_and_percent: true, ..Default::default() };
+    let rewritten = parse_value_literal_with_options("12%", &options).unwrap();
+    assert!(rewritten.heap_size() >= "0.12".len() + "12%".len(), "the original text is counted too");
+
+    let range = parse_value_literal("1..5").unwrap();
+    assert!(range.heap_size() >= 2 * size_of::<Literal>(), "both bounds are boxed");
+}
+
+#[test]
+fn trim_trailing_zeros_keeps_one_fractional_digit() {
+    let trim = |literal: &str| {
+        let ValueLiteral::Decimal(decimal) = parse_value_literal(literal).unwrap().inner else {
+            panic!("expected a decimal: {literal}")
+        };
+        decimal.trim_trailing_zeros().to_string()
+    };
+    assert_eq!(trim("1.500"), "1.5");
+    assert_eq!(trim("1.0"), "1.0");
+    assert_eq!(trim("100.00"), "100.0");
+    assert_eq!(trim("-2.50e10"), "-2.5e10");
+}
+
+#[test]
+fn compact_decimals_drop_redundant_zeros() {
+    let compact = |literal: &str| {
+        let ValueLiteral::Decimal(decimal) = parse_value_literal(literal).unwrap().inner else {
+            panic!("expected a decimal: {literal}")
+        };
+        decimal.to_compact_string()
+    };
+    assert_eq!(compact("0.5"), ".5");
+    assert_eq!(compact("0.0"), "0");
+    assert_eq!(compact("-0.0"), "0");
+    assert_eq!(compact("-0.25"), "-.25");
+    assert_eq!(compact("+007.50"), "7.5");
+    assert_eq!(compact("100.0"), "100");
+    assert_eq!(compact("0.50e-3"), ".5e-3");
+}
+
+#[test]
+fn integer_sign_is_preserved() {
+    let integer =
+        |sign, integral: &str| ValueLiteral::Integer(SignedIntegerLiteral { sign, integral: integral.to_owned() });
+    let [plus, minus, unsigned] = &parse_all(&["+5", "-5", "5"])[..] else { unreachable!() };
+    assert_eq!(plus.inner, integer(Some(Sign::Plus), "5"));
+    assert_eq!(minus.inner, integer(Some(Sign::Minus), "5"));
+    assert_eq!(unsigned.inner, integer(None, "5"));
+    assert_eq!(plus.to_string(), "+5");
+}
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn arbitrary_literal_round_trips(literal: Literal) {
+        let text = literal.to_string();
+        let parsed = parse_value_literal(&text).unwrap();
+        proptest::prop_assert_eq!(&parsed.inner, &literal.inner, "{}", text);
+        proptest::prop_assert!(parsed.validate(&Default::default()).is_ok(), "{}", text);
+    }
+
+    #[test]
+    fn order_preserving_key_agrees_with_total_cmp(lhs: Literal, rhs: Literal) {
+        let key_order = lhs.order_preserving_key().cmp(&rhs.order_preserving_key());
+        proptest::prop_assert_eq!(key_order, lhs.total_cmp(&rhs), "{} and {}", lhs, rhs);
+    }
+
+    #[test]
+    fn canonical_string_is_idempotent(literal: Literal) {
+        let canonical = literal.to_canonical_string();
+        let reparsed = parse_value_literal(&canonical).unwrap();
+        proptest::prop_assert_eq!(reparsed.to_canonical_string(), canonical);
+    }
+}
+
+#[test]
+fn empty_string_in_both_quote_styles() {
+    for input in [r#""""#, "''"] {
+        let parsed = parse_value_literal(input).unwrap();
+        assert_full_span_coverage(&parsed, input);
+        let ValueLiteral::String(string) = &parsed.inner else { panic!("expected a string: {parsed:?}") };
+        assert!(!string.is_block());
+        assert_eq!(string.unescape().unwrap(), "");
+        assert_eq!(parsed.to_string(), input);
+    }
+}
+
+#[test]
+fn magnitude_excludes_sign() {
+    let literals = parse_all(&["+42", "-42", "42", "-1.5e3", "1.5e3"]);
+    let magnitudes = literals.iter().map(|literal| match &literal.inner {
+        ValueLiteral::Integer(integer) => (integer.sign, integer.magnitude()),
+        ValueLiteral::Decimal(decimal) => (decimal.sign, decimal.magnitude()),
+        _ => panic!("expected a number: {literal:?}"),
+    });
+    assert!(magnitudes.clone().take(3).all(|(_, magnitude)| magnitude == "42"));
+    assert!(magnitudes.clone().skip(3).all(|(_, magnitude)| magnitude == "1.5e3"));
+
+    let values: Vec<f64> =
+        magnitudes.map(|(sign, magnitude)| sign.unwrap_or(Sign::Plus).apply(magnitude.parse().unwrap())).collect();
+    assert_eq!(values, [42.0, -42.0, 42.0, -1500.0, 1500.0]);
+}
+
+#[test]
+fn canonical_string_normalises_each_literal_kind() {
+    let cases = [
+        ("+007", "7"),
+        ("-0", "0"),
+        ("-0.00", "0.0"),
+        ("+001.500", "1.5"),
+        ("1.5e+03", "1500.0"),
+        ("15.0e-1", "1.5"),
+        ("0.000012", "0.000012"),
+        ("1.25E30", "1.25e30"),
+        ("-3.0e-10", "-3.0e-10"),
+        ("2024-03-10T02:30", "2024-03-10T02:30:00"),
+        ("2024-03-10T02:30:05.1200", "2024-03-10T02:30:05.12"),
+        ("2024-03-10T02:30:05.000+0530", "2024-03-10T02:30:05+05:30"),
+        ("2024-03-10T02:30-00", "2024-03-10T02:30:00Z"),
+        ("2024-03-10T02:30 Europe/London", "2024-03-10T02:30:00 Europe/London"),
+        ("+2024-03-10", "2024-03-10"),
+        ("P01Y002DT1.50S", "P1Y2DT1.5S"),
+        ("true", "true"),
+        ("'text'", "'text'"),
+    ];
+    for (input, canonical) in cases {
+        let literal = parse_value_literal(input).unwrap();
+        assert_eq!(literal.to_canonical_string(), canonical, "{input}");
+        let reparsed = parse_value_literal(canonical).unwrap();
+        assert_eq!(reparsed.value_type(), literal.value_type(), "{input}");
+        assert_eq!(reparsed.to_canonical_string(), canonical, "{input}");
+    }
+}
+
+#[test]
+fn unit_suffixed_integers_are_expanded_when_allowed() {
+    let options = ParserOptions { allow_unit_suffixes: true, ..Default::default() };
+    let parsed = parse_value_literal_with_options("5k", &options).unwrap();
+    assert_full_span_coverage(&parsed, "5k");
+    assert_eq!(parsed.inner, ValueLiteral::Integer(SignedIntegerLiteral { sign: None, integral: "5000".to_owned() }));
+    assert_eq!(parsed.original(), Some("5k"));
+    assert_eq!(parsed.to_string(), "5000");
+
+    let parsed = parse_value_literal_with_options("-2M", &options).unwrap();
+    assert_eq!(
+        parsed.inner,
+        ValueLiteral::Integer(SignedIntegerLiteral { sign: Some(Sign::Minus), integral: "2000000".to_owned() })
+    );
+
+    assert_eq!(
+        parse_value_literal_with_options("5x", &options),
+        Err(TypeQLError::UnknownUnitSuffix { literal: "5x".to_owned(), suffix: "x".to_owned() }.into())
+    );
+    assert!(parse_value_literal_with_options("5k", &ParserOptions::default()).is_err());
+    assert!(parse_value_literal("5k").is_err());
+}
+
+#[test]
+fn parenthesized_decimals_are_negative_when_allowed() {
+    let options = ParserOptions { allow_parenthesized_negatives: true, ..Default::default() };
+    let parsed = parse_value_literal_with_options("(123.45)", &options).unwrap();
+    assert_full_span_coverage(&parsed, "(123.45)");
+    assert_eq!(
+        parsed.inner,
+        ValueLiteral::Decimal(SignedDecimalLiteral { sign: Some(Sign::Minus), decimal: "123.45".to_owned() })
+    );
+    assert_eq!(parsed.original(), Some("(123.45)"));
+    assert_eq!(parsed.to_string(), "-123.45");
+
+    for unbalanced in ["(123.45", "123.45)", "((123.45)", "(-123.45)", "( 123.45)"] {
+        assert!(parse_value_literal_with_options(unbalanced, &options).is_err(), "{unbalanced}");
+    }
+    let error = parse_value_literal_with_options("(123.45)", &ParserOptions::default()).unwrap_err();
+    assert!(error.to_string().contains("[TQL03]"), "{error}");
+}
+
+#[test]
+fn bare_decimal_points_gain_a_zero_when_allowed() {
+    let options = ParserOptions { allow_bare_decimal_points: true, ..Default::default() };
+    let trailing = parse_value_literal_with_options("5.", &options).unwrap();
+    assert_full_span_coverage(&trailing, "5.");
+    assert_eq!(trailing.inner, decimal(None, "5.0"));
+    assert_eq!(trailing.original(), Some("5."));
+    let leading = parse_value_literal_with_options("-.5", &options).unwrap();
+    assert_eq!(leading.inner, decimal(Some(Sign::Minus), "0.5"));
+    assert_eq!(leading.to_string(), "-0.5");
+
+    for invalid in [".", "-.", "..5", "5..", ". 5"] {
+        assert!(parse_value_literal_with_options(invalid, &options).is_err(), "{invalid}");
+    }
+    assert!(parse_value_literal_with_options(".5", &ParserOptions::default()).is_err());
+}
+
+#[test]
+fn leaf_literals_parse_from_str() {
+    let date: DateFragment = "2023-01-01".parse().unwrap();
+    assert_eq!(
+        date,
+        DateFragment { year: "2023".to_owned(), month: Some("01".to_owned()), day: Some("01".to_owned()) }
+    );
+    assert_eq!(SignedIntegerLiteral::try_from("-5").unwrap().to_string(), "-5");
+    assert_eq!(SignedDecimalLiteral::try_from("1.5e3").unwrap().to_string(), "1.5e3");
+    assert_eq!("P1Y2M".parse::<DurationLiteral>().unwrap().to_string(), "P1Y2M");
+    assert_eq!("10:30:00".parse::<TimeFragment>().unwrap().to_string(), "10:30:00");
+}
+
+#[test]
+fn leaf_literals_reject_other_kinds() {
+    assert_eq!(
+        "true".parse::<DateFragment>(),
+        Err(TypeQLError::UnexpectedLiteralKind { literal: "true".to_owned(), kind: "date".to_owned() }.into())
+    );
+    assert!(SignedIntegerLiteral::try_from("1.5").is_err());
+    assert!(SignedDecimalLiteral::try_from("15").is_err());
+    assert!(DateLiteral::try_from("2023-01-01T10:00").is_err());
+    assert!("2023-01-01 trailing".parse::<DateFragment>().is_err());
+}
+
+#[test]
+fn decimal_mantissa_and_exponent() {
+    let parts = |literal: &str| {
+        let decimal = SignedDecimalLiteral::try_from(literal).unwrap();
+        (decimal.mantissa().to_owned(), decimal.exponent())
+    };
+    assert_eq!(parts("1.5e10"), ("1.5".to_owned(), Ok(Some(10))));
+    assert_eq!(parts("-3.0E-7"), ("3.0".to_owned(), Ok(Some(-7))));
+    assert_eq!(parts("2.5"), ("2.5".to_owned(), Ok(None)));
+    assert_eq!(
+        parts("1.0e3000000000").1,
+        Err(TypeQLError::ExponentOutOfRange {
+            decimal: "1.0e3000000000".to_owned(),
+            source: Some("3000000000".parse::<i32>().unwrap_err())
+        }
+        .into())
+    );
+}
+
+#[test]
+fn significant_digits_ignore_leading_zeros() {
+    let decimal = |decimal: &str| SignedDecimalLiteral::try_from(decimal).unwrap().significant_digits();
+    assert_eq!(decimal("0.00120"), 3);
+    assert_eq!(decimal("-100.0"), 4);
+    assert_eq!(decimal("001.5e10"), 2);
+    assert_eq!(decimal("0.0"), 1);
+    assert_eq!(decimal("0.000"), 1);
+
+    let integer = |integer: &str| IntegerLiteral::try_from(integer).unwrap().significant_digits();
+    assert_eq!(integer("100"), 3);
+    assert_eq!(integer("0042"), 2);
+    assert_eq!(integer("0"), 1);
+    assert_eq!(integer("000"), 1);
+}
+
+#[test]
+fn simple_decimals_have_no_sign_or_exponent() {
+    for simple in ["1.5", "0.0", "007.250"] {
+        assert!(SignedDecimalLiteral::try_from(simple).unwrap().is_simple(), "{simple}");
+    }
+    for complex in ["-1.5", "+1.5", "1.5e3", "1.5E-3", "-0.0e0"] {
+        assert!(!SignedDecimalLiteral::try_from(complex).unwrap().is_simple(), "{complex}");
+    }
+    assert!(!SignedDecimalLiteral { sign: None, decimal: "1_000.5".to_owned() }.is_simple());
+}
+
+#[test]
+fn decimal_to_float_reports_precision_loss() {
+    let lossy = |literal: &str| SignedDecimalLiteral::try_from(literal).unwrap().as_f64_lossy();
+    for (exact, float) in [
+        ("1.5", 1.5),
+        ("-0.25", -0.25),
+        ("0.0", 0.0),
+        ("1.024e6", 1024000.0),
+        ("9007199254740992.0", 9007199254740992.0),
+    ] {
+        assert_eq!(lossy(exact), (float, true), "{exact}");
+    }
+    for (inexact, float) in
+        [("0.1", 0.1), ("1.0e300", 1.0e300), ("9007199254740993.0", 9007199254740992.0), ("1.0e-400", 0.0)]
+    {
+        assert_eq!(lossy(inexact), (float, false), "{inexact}");
+    }
+    assert_eq!(lossy("1.0e400"), (f64::INFINITY, false));
+}
+
+#[test]
+fn signed_radix_integers_apply_the_sign_to_the_decoded_magnitude() {
+    let options = ParserOptions { allow_radix_integers: true, ..Default::default() };
+    for (literal, sign, integral, radix) in [
+        ("-0xFF", Some(Sign::Minus), "255", 16),
+        ("+0b10", Some(Sign::Plus), "2", 2),
+        ("0x7fffffffffffffff", None, "9223372036854775807", 16),
+        ("-0b0", Some(Sign::Minus), "0", 2),
+    ] {
+        let parsed = parse_value_literal_with_options(literal, &options).unwrap();
+        assert_full_span_coverage(&parsed, literal);
+        assert_eq!(parsed.inner, ValueLiteral::Integer(SignedIntegerLiteral { sign, integral: integral.to_owned() }));
+        assert_eq!(parsed.original(), Some(literal));
+        assert_eq!(parsed.radix(), Some(radix), "{literal}");
+    }
+    assert_eq!(parse_value_literal_with_options("255", &options).unwrap().radix(), Some(10));
+    let suffixed = ParserOptions { allow_unit_suffixes: true, ..Default::default() };
+    assert_eq!(parse_value_literal_with_options("-5k", &suffixed).unwrap().radix(), Some(10));
+    assert_eq!(parse_value_literal("1.5").unwrap().radix(), None);
+
+    for invalid in ["0x-FF", "-0x", "0b12", "0xFFg", "- 0xFF"] {
+        assert!(parse_value_literal_with_options(invalid, &options).is_err(), "{invalid}");
+    }
+    let too_long = format!("0x1{}", "0".repeat(32));
+    assert_eq!(
+        parse_value_literal_with_options(&too_long, &options),
+        Err(TypeQLError::IntegerOutOfRange {
+            integer: too_long.clone(),
+            source: Some(u128::from_str_radix(&too_long[2..], 16).unwrap_err())
+        }
+        .into())
+    );
+    assert!(parse_value_literal("-0xFF").is_err());
+}
+
+#[test]
+fn literals_without_spans_outlive_their_input() {
+    fn cache(literal: Literal) -> Box<dyn std::any::Any> {
+        Box::new(literal.without_span())
+    }
+    let input = String::from(" 12%");
+    let parsed = parse_value_literal_with_options(
+        &input,
+        &ParserOptions { allow_currency_and_percent: true, ..Default::default() },
+    )
+    .unwrap();
+    assert!(parsed.span().is_some());
+    let cached = cache(parsed.clone());
+    drop(input);
+
+    let cached = cached.downcast::<Literal>().unwrap();
+    assert_eq!(cached.span(), None);
+    assert!(cached.raw_eq(&parsed));
+    assert_eq!(cached.original(), Some("12%"));
+    assert_eq!(*cached, parsed.with_span(None));
+}
+
+#[test]
+fn strings_are_cast_to_the_annotated_type_when_allowed() {
+    let options = ParserOptions { allow_casts: true, ..Default::default() };
+    for (literal, expected) in [
+        (r#""5"::integer"#, ValueLiteral::Integer(SignedIntegerLiteral { sign: None, integral: "5".to_owned() })),
+        ("'-1.5'::decimal", decimal(Some(Sign::Minus), "1.5")),
+        (r#""2023-01-01"::date"#, ValueLiteral::Date("2023-01-01".parse().unwrap())),
+        (r#""abc"::string"#, ValueLiteral::String(StringLiteral { value: r#""abc""#.to_owned() })),
+        (r#""Europe/London"::timezone"#, ValueLiteral::TimeZone(TimeZone::IANA("Europe/London".to_owned()))),
+        ("'+01:00'::timezone", ValueLiteral::TimeZone(TimeZone::ISO("+01:00".to_owned()))),
+    ] {
+        let parsed = parse_value_literal_with_options(literal, &options).unwrap();
+        assert_full_span_coverage(&parsed, literal);
+        assert_eq!(parsed.inner, expected);
+        assert_eq!(parsed.original(), Some(literal));
+    }
+
+    for (literal, string, value_type, end) in [
+        (r#""abc"::integer"#, r#""abc""#, "integer", 15),
+        (r#""5"::decimal"#, r#""5""#, "decimal", 13),
+        (r#""London"::timezone"#, r#""London""#, "timezone", 19),
+    ] {
+        let span = Span { begin: LineColumn { line: 1, column: 1 }, end: LineColumn { line: 1, column: end } };
+        assert_eq!(
+            parse_value_literal_with_options(literal, &options),
+            Err(TypeQLError::IllegalCoercion {
+                literal: string.to_owned(),
+                value_type: value_type.to_owned(),
+                span: Some(span)
+            }
+            .into())
+        );
+    }
+    assert!(parse_value_literal_with_options(r#""5"::long"#, &options).is_err());
+    assert!(parse_value_literal(r#""5"::integer"#).is_err());
+}
+
+#[test]
+fn mixed_number_separators_are_reported_clearly() {
+    let options = ParserOptions { allow_currency_and_percent: true, ..Default::default() };
+    for (literal, column) in [("1_000,5", 6), ("-1,000_000", 7), ("1_000.000,5", 10)] {
+        for error in [
+            parse_value_literal(literal).unwrap_err(),
+            parse_value_literal_with_options(literal, &options).unwrap_err(),
+        ] {
+            let span = Span { begin: LineColumn { line: 1, column }, end: LineColumn { line: 1, column: column + 1 } };
+            assert_eq!(
+                error,
+                TypeQLError::InconsistentSeparators { literal: literal.to_owned(), span: Some(span) }.into()
+            );
+        }
+    }
+    for consistent in ["1_000_000", "1,000.5", "1.2.3"] {
+        let error = parse_value_literal(consistent).unwrap_err();
+        assert!(error.to_string().contains("[TQL03]"), "{error}");
+    }
+}
+
+#[test]
+fn older_language_versions_reject_newer_syntax() {
+    assert_eq!(Version::LATEST.to_string(), GRAMMAR_VERSION);
+    assert_eq!(ParserOptions::default().language_version, Version::LATEST);
+    assert!(Version::V3_0 < Version::V3_1);
+    let latest = ParserOptions { allow_currency_and_percent: true, allow_partial_dates: true, ..Default::default() };
+    let older = ParserOptions { language_version: Version::V3_0, ..latest.clone() };
+    for literal in ["$1,234.50", "12%", "2023-06"] {
+        assert!(parse_value_literal_with_options(literal, &latest).is_ok(), "{literal}");
+        let error = parse_value_literal_with_options(literal, &older).unwrap_err();
+        assert!(error.to_string().contains("[TQL03]"), "{error}");
+    }
+    assert_eq!(parse_value_literal_with_options("2023", &older).unwrap().to_string(), "2023");
+    assert_eq!(parse_value_literal_with_options("1.5e3", &older).unwrap().to_string(), "1.5e3");
+
+    for literal in [r#""""block""""#, "Europe/London", "+01:00", "1..5"] {
+        assert!(parse_value_literal_with_options(literal, &latest).is_ok(), "{literal}");
+        let error = parse_value_literal_with_options(literal, &older).unwrap_err();
+        assert!(error.to_string().contains("[TQL03]"), "{error}");
+    }
+    let query = r#"match $x has description """block""";"#;
+    assert!(parse_query_with_options(query, &latest).is_ok());
+    let error = parse_query_with_options(query, &older).unwrap_err();
+    assert!(error.to_string().contains("[TQL03]"), "{error}");
+    let literals = iter_value_literals_spanned_with_options("'text' Europe/London", &older).collect::<Vec<_>>();
+    assert!(literals[0].is_ok());
+    assert!(matches!(&literals[1], Err(error) if error.to_string().contains("[TQL51]")), "{literals:?}");
+}
+
+#[test]
+fn lone_signs_are_reported_as_empty_numbers() {
+    let span = Span { begin: LineColumn { line: 1, column: 1 }, end: LineColumn { line: 1, column: 2 } };
+    for literal in ["-", "+"] {
+        let options = ParserOptions { allow_unit_suffixes: true, ..Default::default() };
+        for error in [
+            parse_value_literal(literal).unwrap_err(),
+            parse_value_literal_with_options(literal, &options).unwrap_err(),
+        ] {
+            assert_eq!(error, TypeQLError::EmptyNumericLiteral { span: Some(span) }.into());
+        }
+    }
+    let error = parse_value_literal("--").unwrap_err();
+    assert!(error.to_string().contains("[TQL03]"), "{error}");
+}
+
+#[test]
+fn non_finite_decimals_are_reported_clearly() {
+    for literal in ["∞", "-∞", "-Infinity", "+inf", "NaN"] {
+        let span = Span {
+            begin: LineColumn { line: 1, column: 1 },
+            end: LineColumn { line: 1, column: literal.chars().count() as u32 + 1 },
+        };
+        let error = parse_value_literal(literal).unwrap_err();
+        assert_eq!(error, TypeQLError::NonFiniteDecimal { literal: literal.to_owned(), span: Some(span) }.into());
+        assert!(error.to_string().contains("infinities and NaN are not supported"), "{error}");
+    }
+    let error = parse_value_literal("infinite").unwrap_err();
+    assert!(error.to_string().contains("[TQL03]"), "{error}");
+}
+
+#[test]
+fn spanned_literals_are_iterated_in_source_order() {
+    let input = "2024-01-01  'text'\n  1.5 oops\ttrue";
+    let span =
+        |line, begin, end| Span { begin: LineColumn { line, column: begin }, end: LineColumn { line, column: end } };
+    let mut spans = Vec::new();
+    for literal in iter_value_literals_spanned(input) {
+        match literal {
+            Ok((literal, span)) => {
+                assert_eq!(literal.span(), Some(span));
+                spans.push(span);
+            }
+            Err(error) => assert!(error.to_string().contains("oops"), "{error}"),
+        }
+    }
+    assert_eq!(spans, [span(1, 1, 11), span(1, 13, 19), span(2, 3, 6), span(2, 12, 16)]);
+    let starts = spans.iter().map(|span| (span.begin.line, span.begin.column)).collect::<Vec<_>>();
+    assert!(starts.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn literals_are_parsed_from_utf8_bytes() {
+    let parsed = parse_value_literal_bytes("\"naïve\"".as_bytes()).unwrap();
+    assert_eq!(parsed, parse_value_literal("\"naïve\"").unwrap());
+    assert_eq!(parse_value_literal_bytes(b"2024-01-01").unwrap().value_type(), ValueType::Date);
+
+    assert_eq!(parse_value_literal_bytes(b"\"na\xc3\"").unwrap_err(), TypeQLError::InvalidUtf8 { position: 3 }.into());
+    assert_eq!(parse_value_literal_bytes(b"\xff12").unwrap_err(), TypeQLError::InvalidUtf8 { position: 0 }.into());
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn integers_of_any_size_round_trip_through_bigint() {
+    let digits = "1234567890".repeat(10);
+    for literal in [digits.clone(), format!("-{digits}"), "+007".to_owned(), "-0".to_owned()] {
+        let parsed = parse_value_literal(&literal).unwrap();
+        let ValueLiteral::Integer(integer) = &parsed.inner else { panic!("{literal} is not an integer") };
+        let bigint = integer.as_bigint();
+        assert_eq!(bigint.to_string(), parsed.to_canonical_string(), "{literal}");
+        assert_eq!(SignedIntegerLiteral::from(bigint).to_string(), parsed.to_canonical_string());
+    }
+    assert_eq!(IntegerLiteral { value: digits.clone() }.as_bigint().to_string(), digits);
+}
+
+#[test]
+fn raw_equality_compares_source_text() {
+    let [five, padded_five] = &parse_all(&["5", "05"])[..] else { unreachable!() };
+    assert!(!five.raw_eq(padded_five));
+    assert_eq!(five.total_cmp(padded_five), Ordering::Equal);
+
+    let indented_five = parse_value_literal(" 5").unwrap();
+    assert!(five.raw_eq(&indented_five));
+    assert_ne!(five, &indented_five);
+}
+
+#[test]
+fn custom_null_tokens_parse_as_null() {
+    let options = ParserOptions { null_tokens: vec!["N/A".to_owned(), "NULL".to_owned()], ..Default::default() };
+    let parsed = parse_value_literal_with_options("N/A", &options).unwrap();
+    assert_full_span_coverage(&parsed, "N/A");
+    assert_eq!(parsed.value_type(), ValueType::Null);
+    assert_eq!(parsed.to_string(), "N/A");
+    let null = parse_value_literal_with_options("NULL", &options).unwrap();
+    assert_eq!(parsed.total_cmp(&null), Ordering::Equal);
+
+    assert!(parse_value_literal_with_options("null", &options).is_err());
+    assert!(parse_value_literal_with_options("N/A", &ParserOptions::default()).is_err());
+    let options = ParserOptions { null_tokens: vec!["false".to_owned()], ..Default::default() };
+    assert_eq!(parse_value_literal_with_options("false", &options).unwrap().value_type(), ValueType::Boolean);
+}
+
+#[test]
+fn boolean_keywords_parse_as_strings_when_disabled() {
+    assert!(ParserOptions::default().boolean_keywords);
+    let options = ParserOptions { boolean_keywords: false, ..Default::default() };
+    let parsed = parse_value_literal_with_options("true", &options).unwrap();
+    assert_full_span_coverage(&parsed, "true");
+    assert_eq!(parsed.value_type(), ValueType::String);
+    assert_eq!(parsed.original(), Some("true"));
+    assert_eq!(parsed.to_canonical_string(), "\"true\"");
+    assert_eq!(parse_value_literal_with_options("false", &options).unwrap().value_type(), ValueType::String);
+    assert_eq!(parse_value_literal_with_options("12", &options).unwrap().value_type(), ValueType::Integer);
+
+    assert_eq!(parse_value_literal("true").unwrap().value_type(), ValueType::Boolean);
+    let older = ParserOptions { language_version: Version::V3_0, ..options.clone() };
+    assert_eq!(parse_value_literal_with_options("true", &older).unwrap().value_type(), ValueType::Boolean);
+
+    let query = "match $x has active true;";
+    assert!(parse_query_with_options(query, &options).unwrap().to_string().contains(r#"has active "true""#));
+    assert!(parse_query_with_options(query, &older).unwrap().to_string().contains("has active true"));
+    assert!(parse_query(query).unwrap().to_string().contains("has active true"));
+}
+
+#[test]
+fn boolean_keywords_apply_to_ranges_and_literal_lists() {
+    let options = ParserOptions { boolean_keywords: false, ..Default::default() };
+    let range = parse_value_literal_with_options("true..false", &options).unwrap();
+    let ValueLiteral::Range(range) = &range.inner else { panic!("expected a range, found {range:?}") };
+    assert_eq!(range.start.value_type(), ValueType::String);
+    assert_eq!(range.end.value_type(), ValueType::String);
+
+    let (partial, rest) = parse_value_literal_partial_with_options("true, 1", &options).unwrap();
+    assert_eq!((partial.value_type(), rest), (ValueType::String, ", 1"));
+
+    let types = |literals: Vec<Literal>| literals.iter().map(Literal::value_type).collect::<Vec<_>>();
+    let literals = parse_value_literals_with_options("true 1 false", &options).unwrap();
+    assert_eq!(types(literals), [ValueType::String, ValueType::Integer, ValueType::String]);
+    let spanned = iter_value_literals_spanned_with_options("true 1", &options).map(|item| item.unwrap().0);
+    assert_eq!(types(spanned.collect()), [ValueType::String, ValueType::Integer]);
+    assert_eq!(types(parse_value_literals("true 1").unwrap()), [ValueType::Boolean, ValueType::Integer]);
+}
+
+#[test]
+fn expect_type_checks_the_value_type() {
+    let literal = parse_value_literal(" 2024-01-01").unwrap();
+    assert_eq!(literal.expect_type(ValueType::Date), Ok(&literal));
+    let span = Some(Span { begin: LineColumn { line: 1, column: 2 }, end: LineColumn { line: 1, column: 12 } });
+    let error = TypeQLError::ValueTypeMismatch { expected: "integer".to_owned(), found: "date".to_owned(), span };
+    assert_eq!(literal.expect_type(ValueType::Integer), Err(error.into()));
+}
+
+#[test]
+fn only_rewritten_literals_are_lossy() {
+    for plain in ["007", "1.50", "'a'", "2024-01-01T10:00 Europe/London"] {
+        assert!(!parse_value_literal(plain).unwrap().is_lossy(), "{plain}");
+    }
+    let options = ParserOptions { allow_currency_and_percent: true, ..Default::default() };
+    let currency = parse_value_literal_with_options("$1,234.50", &options).unwrap();
+    assert!(currency.is_lossy());
+    assert_eq!(currency.to_canonical_string(), "1234.5");
+}
+
+#[test]
+fn column_conversion_reports_the_first_invalid_index() {
+    let integers = parse_all(&["1", "-9223372036854775808", "+42"]);
+    assert_eq!(collect_integers(&integers), Ok(vec![1, i64::MIN, 42]));
+    assert_eq!(collect_decimals(&parse_all(&["1.5", "-2.0e3"])), Ok(vec![1.5, -2000.0]));
+    let dates = collect_dates(&parse_all(&["2024-01-01"])).unwrap();
+    assert_eq!(dates[0].to_string(), "2024-01-01");
+
+    let mixed = parse_all(&["1", "2", "3.5", "'4'"]);
+    let span = Some(Span { begin: LineColumn { line: 1, column: 1 }, end: LineColumn { line: 1, column: 4 } });
+    let error =
+        TypeQLError::InvalidColumnValue { index: 2, expected: "integer".to_owned(), literal: "3.5".to_owned(), span };
+    assert_eq!(collect_integers(&mixed), Err(error.into()));
+    let overflowing = parse_all(&["9223372036854775808"]);
+    assert!(collect_integers(&overflowing).unwrap_err().to_string().contains("at index 0"));
+}
+
+#[test]
+fn typed_accessors_fall_back_to_the_default() {
+    let literal = |literal: &str| parse_value_literal(literal).unwrap();
+    assert_eq!(literal("-42").as_i64(), Ok(-42));
+    assert_eq!(literal("-9223372036854775808").as_i64_or(0), i64::MIN);
+    assert_eq!(literal("2.5").as_f64_or(0.0), 2.5);
+    assert!(literal("true").as_bool_or(false));
+
+    let mismatch = literal("'42'");
+    let error = TypeQLError::ValueTypeMismatch {
+        expected: "integer".to_owned(),
+        found: "string".to_owned(),
+        span: mismatch.span(),
+    };
+    assert_eq!(mismatch.as_i64(), Err(error.into()));
+    assert_eq!(mismatch.as_i64_or(7), 7);
+    assert_eq!(literal("42").as_f64_or(1.5), 1.5);
+    assert!(literal("1.0").as_bool_or(true));
+
+    let overflowing = literal("9223372036854775808");
+    let error = TypeQLError::NumberOutOfRange {
+        number: "9223372036854775808".to_owned(),
+        target: "an i64",
+        span: overflowing.span(),
+    };
+    assert_eq!(overflowing.as_i64(), Err(error.into()));
+    assert_eq!(overflowing.as_i64_or(-1), -1);
+    assert_eq!(literal("1.0e400").as_f64_or(-1.0), -1.0);
+}
+
+#[test]
+fn sql_literals_per_dialect() {
+    use SqlDialect::{Generic, Postgres};
+    let sql = |literal: &str, dialect| parse_value_literal(literal).unwrap().to_sql_literal(dialect);
+    assert_eq!(sql(r#""it's \"quoted\"""#, Generic).unwrap(), r#"'it''s "quoted"'"#);
+    assert_eq!(sql("'O\\'Brien'", Postgres).unwrap(), "'O''Brien'");
+    assert_eq!(sql("true", Generic).unwrap(), "TRUE");
+    assert_eq!(sql("+007.50", Generic).unwrap(), "7.5");
+    assert_eq!(sql("2024-01-01", Generic).unwrap(), "DATE '2024-01-01'");
+    assert_eq!(sql("2024-01-01T10:00", Postgres).unwrap(), "TIMESTAMP '2024-01-01 10:00:00'");
+    assert_eq!(sql("2024-01-01T10:00Z", Generic).unwrap(), "TIMESTAMP WITH TIME ZONE '2024-01-01 10:00:00+00:00'");
+    for zero in ["+00:00", "+0000", "-00"] {
+        let expected = "TIMESTAMP WITH TIME ZONE '2024-01-01 10:00:00+00:00'";
+        assert_eq!(sql(&format!("2024-01-01T10:00{zero}"), Generic).unwrap(), expected);
+    }
+    assert_eq!(sql("2024-01-01T10:00+0530", Postgres).unwrap(), "TIMESTAMPTZ '2024-01-01 10:00:00+05:30'");
+    assert_eq!(
+        sql("2024-01-01T10:00 Europe/London", Postgres).unwrap(),
+        "TIMESTAMPTZ '2024-01-01 10:00:00 Europe/London'"
+    );
+    assert_eq!(sql("P1DT2H", Postgres).unwrap(), "INTERVAL 'P1DT2H'");
+
+    let error = TypeQLError::UnsupportedSqlValue { value_type: "duration".to_owned(), dialect: "generic".to_owned() };
+    assert_eq!(sql("P1DT2H", Generic), Err(error.into()));
+    assert!(sql("2024-01-01T10:00 Europe/London", Generic).is_err());
+}
+
+#[test]
+fn partial_parse_returns_the_remaining_input() {
+    let input = "2024-01-01T10:00 Europe/London, 'next'";
+    let (first, rest) = parse_value_literal_partial(input).unwrap();
+    assert_eq!(first.value_type(), ValueType::DateTimeTz);
+    assert_eq!(rest, ", 'next'");
+    let end = first.span().unwrap().end;
+    assert_eq!(end, LineColumn { line: 1, column: (input.len() - rest.len()) as u32 + 1 });
+
+    let (second, rest) = parse_value_literal_partial(rest.trim_start_matches([',', ' '])).unwrap();
+    assert_eq!(second.to_string(), "'next'");
+    assert_eq!(rest, "");
+    assert_eq!(parse_value_literal_partial("12 13").unwrap().1, " 13");
+    let (indented, _) = parse_value_literal_partial("  12").unwrap();
+    assert_eq!(indented.span().unwrap().begin, LineColumn { line: 1, column: 3 });
+    assert!(parse_value_literal_partial("$x 12").is_err());
+}
+
+#[test]
+fn map_value_keeps_span() {
+    let literal = parse_value_literal("  41").unwrap();
+    let span = literal.span();
+    let incremented = literal.map_value(|value| match value {
+        ValueLiteral::Integer(integer) => {
+            let value: i64 = integer.sign.unwrap_or(Sign::Plus).apply(integer.magnitude().parse().unwrap());
+            ValueLiteral::Integer(SignedIntegerLiteral { sign: None, integral: (value + 1).to_string() })
+        }
+        other => other,
+    });
+    assert_eq!(incremented.to_string(), "42");
+    assert_eq!(incremented.span(), span);
+    assert!(span.is_some());
+}
+
+#[test]
+fn literal_sequence_reports_each_bad_literal_to_the_sink() {
+    let input = "1 true bad\n  2024-01-01 5x 'ok' \"open";
+    let mut errors = 0;
+    let literals = parse_value_literals_with_sink(input, |_| errors += 1);
+    assert_eq!(errors, 3);
+    assert_eq!(literals.iter().map(Literal::to_string).collect::<Vec<_>>(), ["1", "true", "2024-01-01", "'ok'"]);
+
+    let date_span = literals[2].span().unwrap();
+    assert_eq!(
+        (date_span.begin, date_span.end),
+        (LineColumn { line: 2, column: 3 }, LineColumn { line: 2, column: 13 })
+    );
+
+    let error = parse_value_literals(input).unwrap_err();
+    assert_eq!(error.to_string().matches("[TQL51]").count(), 3);
+    assert_eq!(parse_value_literals("1.5 'a'\n false").unwrap().len(), 3);
+    let literals = parse_value_literals("1\u{a0}2\u{2003}true").unwrap();
+    assert_eq!(literals.iter().map(Literal::to_string).collect::<Vec<_>>(), ["1", "2", "true"]);
+}
+
+#[test]
+fn integer_sign_and_parity_predicates() {
+    let integer = |literal: &str| SignedIntegerLiteral::try_from(literal).unwrap();
+    for (literal, is_negative, is_even) in [
+        ("0", false, true),
+        ("-0", false, true),
+        ("+0", false, true),
+        ("-7", true, false),
+        ("-10", true, true),
+        ("+3", false, false),
+        ("123456789012345678901234567890", false, true),
+        ("99999999999999999999999999999", false, false),
+    ] {
+        assert_eq!(integer(literal).is_negative(), is_negative, "{literal}");
+        assert_eq!(integer(literal).is_even(), is_even, "{literal}");
+    }
+}
+
+#[test]
+fn strings_are_requoted_with_double_quotes() {
+    let double_quoted = |literal: &str| StringLiteral { value: literal.to_owned() }.to_double_quoted().unwrap();
+    assert_eq!(double_quoted(r#"'he said "hi" and \'bye\''"#), r#""he said \"hi\" and 'bye'""#);
+    assert_eq!(double_quoted(r#""already \"double\"""#), r#""already \"double\"""#);
+    assert_eq!(double_quoted(r"'tab\there\\'"), r#""tab\there\\""#);
+    assert_eq!(double_quoted(r#""""block "quoted" 'text'""""#), r#""block \"quoted\" 'text'""#);
+
+    let requoted = StringLiteral { value: double_quoted(r#"'it"s'"#) };
+    assert_eq!(requoted.unescape().unwrap(), r#"it"s"#);
+}
+
+#[test]
+fn display_width_counts_display_characters() {
+    for input in [
+        "true",
+        "-42",
+        "+1.5e-3",
+        "2024-02-29",
+        "2024-02-29T23:59:59.123456789",
+        "2024-02-29T10:00 America/Argentina/Buenos_Aires",
+        "2024-02-29T10:00+05:30",
+        "P1Y2M3DT4H5M6.5S",
+        r#""tab\there \"quoted\"""#,
+        "'日本語のテキスト'",
+        r#""""block "with" quotes""""#,
+    ] {
+        let parsed = parse_value_literal(input).unwrap();
+        assert_eq!(parsed.display_width(), parsed.to_string().chars().count(), "{input}");
+    }
+    assert_eq!(parse_value_literal("'日本'").unwrap().display_width(), 4);
+}
+
+#[test]
+fn literal_rules_map_to_value_types() {
+    for (rule, example, value_type) in [
+        (Rule::quoted_string_literal, "'text'", ValueType::String),
+        (Rule::block_string_literal, r#""""text""""#, ValueType::String),
+        (Rule::boolean_literal, "true", ValueType::Boolean),
+        (Rule::signed_integer, "-5", ValueType::Integer),
+        (Rule::signed_decimal, "-5.0", ValueType::Decimal),
+        (Rule::datetime_tz_literal, "2024-01-01T00:00Z", ValueType::DateTimeTz),
+        (Rule::datetime_literal, "2024-01-01T00:00", ValueType::DateTime),
+        (Rule::date_literal, "2024-01-01", ValueType::Date),
+        (Rule::duration_literal, "P1D", ValueType::Duration),
+        (Rule::timezone_literal, "Europe/London", ValueType::TimeZone),
+    ] {
+        let node = TypeQLParser::parse(Rule::value_literal, example).unwrap().next().unwrap();
+        assert_eq!(node.into_inner().next().unwrap().as_rule(), rule, "{example}");
+        assert_eq!(rule_to_value_type(rule), Some(value_type), "{rule:?}");
+        assert_eq!(parse_value_literal(example).unwrap().value_type(), value_type, "{example}");
+    }
+    for rule in [Rule::value_literal, Rule::integer_literal, Rule::date_fragment, Rule::currency_decimal, Rule::query] {
+        assert_eq!(rule_to_value_type(rule), None, "{rule:?}");
+    }
+}
+
+#[test]
+fn parsed_literals_verify_their_round_trip() {
+    for input in ["true", "-5", "1.5e-3", "'text'", "2024-01-01T10:00:00.5 Europe/London", "P1W"] {
+        assert_eq!(parse_value_literal(input).unwrap().verify_roundtrip(), Ok(()), "{input}");
+    }
+
+    let options = ParserOptions { allow_currency_and_percent: true, ..Default::default() };
+    assert_eq!(parse_value_literal_with_options("$1,000", &options).unwrap().verify_roundtrip(), Ok(()));
+
+    let options = ParserOptions { allow_partial_dates: true, ..Default::default() };
+    let year = parse_value_literal_with_options("2023", &options).unwrap();
+    let error = year.verify_roundtrip().unwrap_err();
+    assert!(error.to_string().contains("[TQL52]") && error.to_string().contains("Integer"), "{error}");
+
+    let options = ParserOptions { null_tokens: vec!["NULL".to_owned()], ..Default::default() };
+    assert!(parse_value_literal_with_options("NULL", &options).unwrap().verify_roundtrip().is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn literals_round_trip_through_json() {
+    for input in ["true", "-1.5e3", "'text'", "2024-01-01T10:00Z", "P1Y2MT3S"] {
+        let parsed = parse_value_literal(input).unwrap();
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(serde_json::from_str::<Literal>(&json).unwrap(), parsed, "{json}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn literals_are_built_from_json_scalars() {
+    use serde_json::json;
+
+    for (json, value_type, text) in [
+        (json!("he said \"hi\""), ValueType::String, r#""he said \"hi\"""#),
+        (json!(true), ValueType::Boolean, "true"),
+        (json!(false), ValueType::Boolean, "false"),
+        (json!(42), ValueType::Integer, "42"),
+        (json!(-7), ValueType::Integer, "-7"),
+        (json!(u64::MAX), ValueType::Integer, "18446744073709551615"),
+        (json!(1.5), ValueType::Decimal, "1.5"),
+        (json!(-0.25), ValueType::Decimal, "-0.25"),
+        (json!(2.0), ValueType::Decimal, "2.0"),
+        (json!(1.5e300), ValueType::Decimal, "1.5e+300"),
+        (json!(1e-7), ValueType::Decimal, "1.0e-7"),
+    ] {
+        let literal = Literal::from_json(&json).unwrap();
+        assert_eq!((literal.value_type(), literal.to_string().as_str()), (value_type, text), "{json}");
+        assert_eq!(parse_value_literal(text).unwrap().inner, literal.inner, "{json}");
+    }
+
+    for (json, kind) in [(json!(null), "null"), (json!([1, 2]), "array"), (json!({ "a": 1 }), "object")] {
+        let error = TypeQLError::UnsupportedJsonValue { kind: kind.to_owned() };
+        assert_eq!(Literal::from_json(&json), Err(error.into()));
+    }
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn literal_json_schema_names_each_variant() {
+    let schema = serde_json::to_string(&schemars::schema_for!(Literal)).unwrap();
+    for tag in
+        ["Boolean", "Integer", "Decimal", "Date", "DateTime", "DateTimeTz", "Duration", "String", "Struct", "Null"]
+    {
+        assert!(schema.contains(&format!("\"{tag}\"")), "{tag} missing from {schema}");
+    }
+    for tag in ["IANA", "ISO", "Utc", "Weeks", "DateAndTime"] {
+        assert!(schema.contains(&format!("\"{tag}\"")), "{tag} missing from {schema}");
+    }
+}
+
+#[test]
+fn integer_arithmetic_is_checked_at_the_i128_boundaries() {
+    let integer = |literal: &str| SignedIntegerLiteral::try_from(literal).unwrap();
+    let sum = |lhs: &str, rhs: &str| integer(lhs).checked_add(&integer(rhs)).map(|sum| sum.to_string());
+    let difference = |lhs: &str, rhs: &str| integer(lhs).checked_sub(&integer(rhs)).map(|sum| sum.to_string());
+
+    assert_eq!(sum("+0002", "-5"), Ok("-3".to_owned()));
+    assert_eq!(sum("-0", "0"), Ok("0".to_owned()));
+    assert_eq!(sum(&i64::MAX.to_string(), "1"), Ok("9223372036854775808".to_owned()));
+    assert_eq!(difference(&i64::MIN.to_string(), "1"), Ok("-9223372036854775809".to_owned()));
+    assert_eq!(sum(&i128::MAX.to_string(), "0"), Ok(i128::MAX.to_string()));
+    assert_eq!(difference(&i128::MIN.to_string(), "0"), Ok(i128::MIN.to_string()));
+
+    let max = i128::MAX.to_string();
+    let out_of_range = |integer: String, source| Err(TypeQLError::IntegerOutOfRange { integer, source }.into());
+    assert_eq!(sum(&max, "1"), out_of_range(format!("{max} + 1"), None));
+    assert_eq!(difference(&i128::MIN.to_string(), "1"), out_of_range(format!("{} - 1", i128::MIN), None));
+    let too_large = "170141183460469231731687303715884105728";
+    let overflow = too_large.parse::<i128>().unwrap_err();
+    assert_eq!(sum(too_large, "-1"), out_of_range(too_large.to_owned(), Some(overflow)));
+}
+
+#[test]
+fn overflow_errors_expose_their_source() {
+    use std::error::Error;
+
+    let too_large = SignedIntegerLiteral::try_from("170141183460469231731687303715884105728").unwrap();
+    let error = too_large.checked_add(&SignedIntegerLiteral::try_from("1").unwrap()).unwrap_err();
+    let source = error.source().expect("an integer overflow has a source");
+    assert_eq!(source.to_string(), "number too large to fit in target type");
+
+    let sum_overflow = SignedIntegerLiteral::try_from(&*i128::MAX.to_string())
+        .unwrap()
+        .checked_add(&SignedIntegerLiteral::try_from("1").unwrap())
+        .unwrap_err();
+    assert!(sum_overflow.source().is_none());
+
+    let exponent = SignedDecimalLiteral::try_from("1.0e3000000000").unwrap().exponent().unwrap_err();
+    assert!(exponent.source().is_some());
+}
+
+#[test]
+fn strings_that_could_be_typed_values_are_detected() {
+    let string = |literal: &str| StringLiteral { value: literal.to_owned() };
+    let date_like = string(r#""2023-01-01""#);
+    assert!(date_like.could_be(ValueType::Date));
+    assert!(!date_like.could_be(ValueType::DateTime));
+    assert!(!date_like.could_be(ValueType::Integer));
+
+    let integer_like = string("'-42'");
+    assert!(integer_like.could_be(ValueType::Integer));
+    assert!(!integer_like.could_be(ValueType::Decimal));
+    assert!(!integer_like.could_be(ValueType::String));
+
+    assert!(string(r#""""true""""#).could_be(ValueType::Boolean));
+    assert!(!string("'42.0'").could_be(ValueType::Integer));
+    assert!(!string("'42 apples'").could_be(ValueType::Integer));
+}
+
+#[test]
+fn literals_downcast_to_their_own_type_only() {
+    let literals =
+        parse_all(&["true", "5", "5.0", "2024-01-01", "2024-01-01T00:00", "2024-01-01T00:00Z", "P1D", "'text'"]);
+    let matches = |literal: &Literal| {
+        [
+            literal.as_boolean().is_some(),
+            literal.as_integer().is_some(),
+            literal.as_decimal().is_some(),
+            literal.as_date().is_some(),
+            literal.as_datetime().is_some(),
+            literal.as_datetime_tz().is_some(),
+            literal.as_duration().is_some(),
+            literal.as_string().is_some(),
+        ]
+    };
+    for (index, literal) in literals.iter().enumerate() {
+        let expected: Vec<bool> = (0..literals.len()).map(|other| other == index).collect();
+        assert_eq!(matches(literal).to_vec(), expected, "{literal}");
+    }
+
+    assert_eq!(literals[1].as_integer().unwrap().to_string(), "5");
+    assert_eq!(literals[1].clone().into_integer().unwrap().to_string(), "5");
+    assert_eq!(literals[1].clone().into_decimal(), None);
+    assert_eq!(literals[0].clone().into_boolean().unwrap().value, "true");
+    assert_eq!(literals[3].clone().into_date().unwrap().to_string(), "2024-01-01");
+    assert_eq!(literals[4].clone().into_datetime().unwrap().to_string(), "2024-01-01T00:00");
+    assert_eq!(literals[5].clone().into_datetime_tz().unwrap().timezone, TimeZone::Utc);
+    assert_eq!(literals[6].clone().into_duration().unwrap().to_string(), "P1D");
+    assert_eq!(literals[7].clone().into_string().unwrap().unescape().unwrap(), "text");
+    assert_eq!(literals[7].clone().into_integer(), None);
+}
+
+#[test]
+fn built_strings_reparse_to_their_content_under_each_escape_policy() {
+    let content = "naïve \"café\" \\ 日本 🦀\nend";
+    let minimal = StringLiteral::build(content, EscapePolicy::Minimal);
+    assert_eq!(minimal.value, "\"naïve \\\"café\\\" \\\\ 日本 🦀\nend\"");
+    let ascii = StringLiteral::build(content, EscapePolicy::AsciiOnly);
+    assert_eq!(ascii.value, r#""na\u{ef}ve \"caf\u{e9}\" \\ \u{65e5}\u{672c} \u{1f980}"#.to_owned() + "\nend\"");
+    assert!(ascii.value.is_ascii());
+
+    for built in [minimal, ascii] {
+        let reparsed = parse_value_literal(&built.value).unwrap().into_string().unwrap();
+        assert_eq!(reparsed.unescape().unwrap(), content, "{}", built.value);
+    }
+
+    for invalid in [r#""\u{}""#, r#""\u{1234567}""#, r#""\u{d800}""#, r#""\u{+41}""#, r#""\u41""#] {
+        let string = StringLiteral { value: invalid.to_owned() };
+        assert!(string.unescape().is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn unescaped_chars_stop_early_without_decoding_the_rest() {
+    let string = StringLiteral { value: r#""ab\tc\qd""#.to_owned() };
+    let prefix: Vec<_> = string.unescaped_chars().take_while(|char| char != &Ok('\t')).collect();
+    assert_eq!(prefix, [Ok('a'), Ok('b')]);
+    assert_eq!(string.unescaped_chars().position(|char| char == Ok('c')), Some(3));
+
+    let block = StringLiteral { value: r#""""a\q""""#.to_owned() };
+    assert_eq!(block.unescaped_chars().collect::<Result<String, _>>(), Ok(r"a\q".to_owned()));
+}
+
+#[test]
+fn unescaped_chars_surface_a_mid_string_escape_error() {
+    let string = StringLiteral { value: r#""ab\qcd""#.to_owned() };
+    let chars: Vec<_> = string.unescaped_chars().collect();
+    let error = TypeQLError::InvalidStringEscape { full_string: r"ab\qcd".to_owned(), escape: r"\q".to_owned() };
+    assert_eq!(chars, [Ok('a'), Ok('b'), Err(error.clone())]);
+    assert_eq!(string.unescape(), Err(error.into()));
+}
+
+#[test]
+fn range_literals_have_bounds_of_one_type() {
+    let parsed = parse_value_literal("5..10").unwrap();
+    assert_full_span_coverage(&parsed, "5..10");
+    assert_eq!(parsed.value_type(), ValueType::Range);
+    let range = parsed.as_range().unwrap();
+    assert_eq!(range.start.to_string(), "5");
+    assert_eq!(range.end.to_string(), "10");
+    assert!(range.inclusive_end);
+    assert_eq!(parsed.to_string(), "5..10");
+
+    let dates = parse_value_literal("2023-01-01..<2024-01-01").unwrap();
+    assert_full_span_coverage(&dates, "2023-01-01..<2024-01-01");
+    let range = dates.as_range().unwrap();
+    assert_eq!(range.start.value_type(), ValueType::Date);
+    assert!(!range.inclusive_end);
+    assert_eq!(dates.to_string(), "2023-01-01..<2024-01-01");
+    assert_eq!(parse_value_literal("+05..010").unwrap().to_canonical_string(), "5..10");
+    let options = ValidationOptions { max_year: Some(2023), ..Default::default() };
+    assert!(dates.validate(&options).is_err());
+
+    let span = Some(Span { begin: LineColumn { line: 1, column: 1 }, end: LineColumn { line: 1, column: 14 } });
+    let error = TypeQLError::MixedRangeTypes { start: "integer".to_owned(), end: "date".to_owned(), span };
+    assert_eq!(parse_value_literal("1..2024-01-01"), Err(error.into()));
+    assert!(parse_query("define attribute age, value integer @range(0..150);").is_ok());
+}
+
+#[test]
+fn range_literals_are_checked_against_the_parser_options() {
+    let options = ParserOptions { max_string_len: Some(3), ..Default::default() };
+    let span = Some(Span { begin: LineColumn { line: 1, column: 6 }, end: LineColumn { line: 1, column: 12 } });
+    let error = TypeQLError::StringLiteralTooLong { len: 6, max: 3, span };
+    assert_eq!(parse_value_literal_with_options(r#""a".."abcd""#, &options), Err(error.into()));
+
+    let options = ParserOptions { max_nodes: Some(3), ..Default::default() };
+    let error = TypeQLError::InputTooComplex { limit: 3 };
+    assert_eq!(parse_value_literal_with_options("1..5", &options), Err(error.into()));
+
+    let older = ParserOptions { language_version: Version::V3_0, ..Default::default() };
+    let error = parse_value_literal_with_options("1..5", &older).unwrap_err();
+    assert!(error.to_string().contains("[TQL03]"), "{error}");
+}
+
+#[test]
+fn reoffset_places_a_literal_within_a_larger_input() {
+    // the literal is the third whitespace-separated token, after two that are not literals
+    let document = "match\n  $x 2024-01-01T10:00 Europe/London";
+    let (_, expected) = iter_value_literals_spanned(document).nth(2).unwrap().unwrap();
+    let mut literal = parse_value_literal("2024-01-01T10:00 Europe/London").unwrap();
+    literal.reoffset(LineColumn { line: 2, column: 6 });
+    assert_eq!(literal.span(), Some(expected));
+    assert_eq!(literal.all_spans()[1].begin, expected.begin);
+
+    let mut range = parse_value_literal("1..10").unwrap();
+    range.reoffset(LineColumn { line: 3, column: 10 });
+    let bounds = range.into_range().unwrap();
+    assert_eq!(bounds.end.span().unwrap().begin, LineColumn { line: 3, column: 13 });
+
+    let mut far = parse_value_literal("1").unwrap();
+    far.reoffset(LineColumn { line: 1, column: u32::MAX });
+    assert_eq!(far.span().unwrap().end.column, u32::MAX);
+}
+
+#[test]
+fn offsetting_a_zero_line_or_column_gives_none() {
+    let origin = LineColumn { line: 2, column: 6 };
+    assert_eq!(LineColumn { line: 1, column: 3 }.offset_to(origin), Some(LineColumn { line: 2, column: 8 }));
+    assert_eq!(LineColumn { line: 3, column: 3 }.offset_to(origin), Some(LineColumn { line: 4, column: 3 }));
+    assert_eq!(LineColumn { line: 0, column: 3 }.offset_to(origin), None);
+    assert_eq!(LineColumn { line: 1, column: 0 }.offset_to(origin), None);
+    assert_eq!(LineColumn { line: 2, column: 0 }.offset_to(origin), None);
+
+    let span = Span { begin: LineColumn { line: 1, column: 1 }, end: LineColumn { line: 0, column: 0 } };
+    assert_eq!(span.offset_to(origin), None);
+    let mut literal = Literal::new(Some(span), parse_value_literal("1").unwrap().inner);
+    literal.reoffset(origin);
+    assert_eq!(literal.span(), None);
+}
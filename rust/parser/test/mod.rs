@@ -4,18 +4,23 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::parse_query;
+use crate::{
+    common::{LineColumn, Spanned},
+    parse_query, Literal,
+};
 
 mod aggregate;
 mod autogenerated;
 mod builder;
 mod builtin_functions;
+mod datetime;
 mod disjunctions;
 mod error;
 mod fetch;
 mod group;
 mod group_aggregate;
 mod list;
+mod literal;
 mod match_queries;
 mod modifiers;
 mod nonquery;
@@ -35,6 +40,29 @@ macro_rules! assert_valid_eq_repr {
 }
 use assert_valid_eq_repr;
 
+/// Asserts that a literal parsed from `input` carries a span covering exactly that input. Call it from the tests of any
+/// new literal visitor to catch visitors that drop or truncate the span of the node they visit.
+///
+/// The spans of value fragments (dates, times, time zones) must also lie within the literal's span, in order and
+/// without overlapping.
+pub(super) fn assert_full_span_coverage(literal: &Literal, input: &str) {
+    let input = input.trim_end();
+    let span = literal.span().unwrap_or_else(|| panic!("literal parsed from {input:?} has no span"));
+    let last_line = input.rsplit('\n').next().unwrap_or_default();
+    let end = LineColumn { line: input.lines().count().max(1) as u32, column: last_line.chars().count() as u32 + 1 };
+    assert_eq!(span.begin, LineColumn { line: 1, column: 1 }, "span of {input:?} starts late");
+    assert_eq!(span.end, end, "span of {input:?} ends early or late");
+
+    let position = |position: LineColumn| (position.line, position.column);
+    let mut previous_end = position(span.begin);
+    for fragment in literal.all_spans().into_iter().skip(1) {
+        assert!(previous_end <= position(fragment.begin), "fragment spans of {input:?} overlap or lie outside it");
+        assert!(position(fragment.begin) <= position(fragment.end), "fragment span of {input:?} is reversed");
+        previous_end = position(fragment.end);
+    }
+    assert!(previous_end <= position(span.end), "fragment spans of {input:?} lie outside it");
+}
+
 // #[test]
 fn tmp() {
     let query_string = r#"
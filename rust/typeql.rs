@@ -24,7 +24,12 @@ pub use crate::{
     variable::Variable,
 };
 use crate::{
-    parser::{visit_eof_definition_function, visit_eof_definition_struct, visit_eof_label, visit_eof_query},
+    common::{error::TypeQLError, Span, Spanned},
+    parser::{
+        visit_eof_definition_function, visit_eof_definition_struct, visit_eof_label, visit_eof_query,
+        visit_eof_query_with_options, visit_eof_value_literal, visit_eof_value_literal_with_options,
+        visit_value_literal_partial, visit_value_literals, ParserOptions, ValueLiterals,
+    },
     schema::definable::Struct,
 };
 
@@ -47,6 +52,10 @@ pub fn parse_query(typeql_query: &str) -> Result<Query> {
     visit_eof_query(typeql_query.trim_end())
 }
 
+pub fn parse_query_with_options(typeql_query: &str, options: &ParserOptions) -> Result<Query> {
+    visit_eof_query_with_options(typeql_query.trim_end(), options)
+}
+
 pub fn parse_label(typeql_label: &str) -> Result<Label> {
     visit_eof_label(typeql_label)
 }
@@ -58,3 +67,90 @@ pub fn parse_definition_function(typeql_function: &str) -> Result<Function> {
 pub fn parse_definition_struct(typeql_struct: &str) -> Result<Struct> {
     visit_eof_definition_struct(typeql_struct.trim_end())
 }
+
+pub fn parse_value_literal(typeql_literal: &str) -> Result<Literal> {
+    visit_eof_value_literal(typeql_literal.trim_end())
+}
+
+/// Parses the literal at the start of the input, returning it with the input that follows it, e.g. for a tokenizer
+/// that reads a literal and then continues with its own syntax. Only whitespace may precede the literal, which ends
+/// where the grammar stops matching, so the remainder may start with whitespace or any other text. Spans are within
+/// the input.
+pub fn parse_value_literal_partial(typeql_input: &str) -> Result<(Literal, &str)> {
+    visit_value_literal_partial(typeql_input, &ParserOptions::default())
+}
+
+/// Parses the literal at the start of the input as [`parse_value_literal_partial`] does, rejecting it when it exceeds
+/// the limits of `options`. The literal ends where the strict grammar stops matching, so lenient forms are not read.
+pub fn parse_value_literal_partial_with_options<'a>(
+    typeql_input: &'a str,
+    options: &ParserOptions,
+) -> Result<(Literal, &'a str)> {
+    visit_value_literal_partial(typeql_input, options)
+}
+
+/// Parses a literal from bytes, e.g. read straight from a file, checking that they are UTF-8 without a separate
+/// conversion to `str`.
+pub fn parse_value_literal_bytes(typeql_literal: &[u8]) -> Result<Literal> {
+    let typeql_literal = std::str::from_utf8(typeql_literal)
+        .map_err(|error| TypeQLError::InvalidUtf8 { position: error.valid_up_to() })?;
+    parse_value_literal(typeql_literal)
+}
+
+/// Parses whitespace-separated literals, reporting every malformed literal rather than stopping at the first.
+pub fn parse_value_literals(typeql_literals: &str) -> Result<Vec<Literal>> {
+    let mut errors = Vec::new();
+    let literals = visit_value_literals(typeql_literals, &ParserOptions::default(), |error| errors.push(error));
+    if errors.is_empty() {
+        Ok(literals)
+    } else {
+        Err(errors.into())
+    }
+}
+
+/// Parses whitespace-separated literals as [`parse_value_literals`] does, reporting each literal that exceeds the
+/// limits of `options` as malformed. The literals are split by the strict grammar, so lenient forms are not read.
+pub fn parse_value_literals_with_options(typeql_literals: &str, options: &ParserOptions) -> Result<Vec<Literal>> {
+    let mut errors = Vec::new();
+    let literals = visit_value_literals(typeql_literals, options, |error| errors.push(error));
+    if errors.is_empty() {
+        Ok(literals)
+    } else {
+        Err(errors.into())
+    }
+}
+
+/// Parses whitespace-separated literals, passing each malformed literal's error to `sink` as it is found, and
+/// returning the literals that parsed.
+pub fn parse_value_literals_with_sink(typeql_literals: &str, sink: impl FnMut(TypeQLError)) -> Vec<Literal> {
+    visit_value_literals(typeql_literals, &ParserOptions::default(), sink)
+}
+
+/// Parses whitespace-separated literals lazily, in source order, pairing each with its span in the input, e.g. for an
+/// editor to offer information on each literal. A malformed literal is an error, after which parsing continues.
+pub fn iter_value_literals_spanned(typeql_literals: &str) -> impl Iterator<Item = Result<(Literal, Span)>> + '_ {
+    iter_value_literals_spanned_with_options(typeql_literals, &ParserOptions::default())
+}
+
+/// Parses whitespace-separated literals lazily as [`iter_value_literals_spanned`] does, with each literal that exceeds
+/// the limits of `options` an error. The literals are split by the strict grammar, so lenient forms are not read.
+pub fn iter_value_literals_spanned_with_options<'a>(
+    typeql_literals: &'a str,
+    options: &ParserOptions,
+) -> impl Iterator<Item = Result<(Literal, Span)>> + 'a {
+    ValueLiterals::new(typeql_literals, options.clone()).map(|literal| {
+        let literal = literal?;
+        let span = literal.span().expect("literals parsed from the input are spanned");
+        Ok((literal, span))
+    })
+}
+
+/// Rewrites every literal in the query in its [canonical form](Literal::to_canonical_string), leaving the text around
+/// the literals, including whitespace and comments, as written.
+pub fn normalize_literals(typeql_query: &str) -> Result<String> {
+    parser::normalize_literals(typeql_query)
+}
+
+pub fn parse_value_literal_with_options(typeql_literal: &str, options: &ParserOptions) -> Result<Literal> {
+    visit_eof_value_literal_with_options(typeql_literal.trim_end(), options)
+}
@@ -0,0 +1,165 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! `proptest` strategies generating literals that parse back to themselves, and that pass validation with the
+//! default options: dates are calendar-valid and times have no leap seconds. Struct literals cannot be written in a
+//! value position, so they are never generated.
+
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    option, prop_oneof,
+    sample::select,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use super::{
+    validation::days_in_month, BooleanLiteral, DateFragment, DateLiteral, DateTimeLiteral, DateTimeTZLiteral,
+    DurationDate, DurationLiteral, DurationTime, IntegerLiteral, Literal, NumericLiteral, Sign, SignedDecimalLiteral,
+    SignedIntegerLiteral, StringLiteral, TimeFragment, TimeZone, ValueLiteral,
+};
+
+const IANA_TIME_ZONES: [&str; 6] = [
+    "Europe/London",
+    "America/New_York",
+    "Asia/Kolkata",
+    "Australia/Lord_Howe",
+    "America/Argentina/Buenos_Aires",
+    "UTC",
+];
+
+macro_rules! impl_arbitrary {
+    ($($type:ty => $strategy:expr;)*) => {$(
+        impl Arbitrary for $type {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                $strategy.boxed()
+            }
+        }
+    )*};
+}
+
+impl_arbitrary! {
+    Literal => any::<ValueLiteral>().prop_map(|inner| Literal::new(None, inner));
+
+    ValueLiteral => prop_oneof![
+        any::<BooleanLiteral>().prop_map(ValueLiteral::Boolean),
+        any::<SignedIntegerLiteral>().prop_map(ValueLiteral::Integer),
+        any::<SignedDecimalLiteral>().prop_map(ValueLiteral::Decimal),
+        any::<DateLiteral>().prop_map(ValueLiteral::Date),
+        any::<DateTimeLiteral>().prop_map(ValueLiteral::DateTime),
+        any::<DateTimeTZLiteral>().prop_map(ValueLiteral::DateTimeTz),
+        any::<DurationLiteral>().prop_map(ValueLiteral::Duration),
+        any::<StringLiteral>().prop_map(ValueLiteral::String),
+        any::<TimeZone>()
+            .prop_filter("a standalone time zone is an offset with a colon or an IANA name with an area", |timezone| {
+                match timezone {
+                    TimeZone::Utc => true,
+                    TimeZone::ISO(offset) => offset.contains(':'),
+                    TimeZone::IANA(name) => name.contains('/'),
+                }
+            })
+            .prop_map(ValueLiteral::TimeZone),
+    ];
+
+    BooleanLiteral => select(vec!["true", "false"]).prop_map(|value| BooleanLiteral { value: value.to_owned() });
+
+    Sign => prop_oneof![Just(Sign::Plus), Just(Sign::Minus)];
+
+    IntegerLiteral => any::<u32>().prop_map(|value| IntegerLiteral { value: value.to_string() });
+
+    NumericLiteral => prop_oneof![
+        any::<u32>().prop_map(|integral| integral.to_string()),
+        (any::<u32>(), "[0-9]{1,9}").prop_map(|(integral, fraction)| format!("{integral}.{fraction}")),
+    ]
+    .prop_map(|value| NumericLiteral { value });
+
+    SignedIntegerLiteral => (any::<Option<Sign>>(), any::<u64>())
+        .prop_map(|(sign, integral)| SignedIntegerLiteral { sign, integral: integral.to_string() });
+
+    SignedDecimalLiteral => {
+        let exponent = option::of((any::<Option<Sign>>(), 0..300u32));
+        (any::<Option<Sign>>(), any::<u32>(), "[0-9]{1,9}", exponent).prop_map(|(sign, integral, fraction, exponent)| {
+            let exponent = match exponent {
+                None => String::new(),
+                Some((None, exponent)) => format!("e{exponent}"),
+                Some((Some(sign), exponent)) => format!("e{sign}{exponent}"),
+            };
+            SignedDecimalLiteral { sign, decimal: format!("{integral}.{fraction}{exponent}") }
+        })
+    };
+
+    StringLiteral => prop_oneof![
+        string_content(r#"\""#).prop_map(|content| format!("\"{content}\"")),
+        string_content(r"\'").prop_map(|content| format!("'{content}'")),
+        "[^\"]*".prop_map(|content| format!("\"\"\"{content}\"\"\"")),
+    ]
+    .prop_map(|value| StringLiteral { value });
+
+    DateFragment => (1..=9999i32, 1..=12u32)
+        .prop_flat_map(|(year, month)| (Just(year), Just(month), 1..=days_in_month(year.into(), month)))
+        .prop_map(|(year, month, day)| DateFragment {
+            year: format!("{year:04}"),
+            month: Some(format!("{month:02}")),
+            day: Some(format!("{day:02}")),
+        });
+
+    TimeFragment => (0..24u32, 0..60u32, option::of((0..60u32, option::of("[0-9]{1,9}"))))
+        .prop_map(|(hour, minute, second)| {
+            let (second, second_fraction) = match second {
+                None => (None, None),
+                Some((second, fraction)) => (Some(format!("{second:02}")), fraction),
+            };
+            TimeFragment { hour: format!("{hour:02}"), minute: format!("{minute:02}"), second, second_fraction }
+        });
+
+    TimeZone => prop_oneof![
+        Just(TimeZone::Utc),
+        (any::<Sign>(), 0..=14u32, select(vec!["", ":"]), option::of(select(vec![0u32, 30, 45])))
+            .prop_map(|(sign, hours, separator, minutes)| {
+                let minutes = minutes.map(|minutes| format!("{separator}{minutes:02}")).unwrap_or_default();
+                TimeZone::ISO(format!("{sign}{hours:02}{minutes}"))
+            }),
+        select(IANA_TIME_ZONES.to_vec()).prop_map(|name| TimeZone::IANA(name.to_owned())),
+    ];
+
+    DateLiteral => any::<DateFragment>().prop_map(|date| DateLiteral { date });
+
+    DateTimeLiteral => (any::<DateFragment>(), any::<TimeFragment>())
+        .prop_map(|(date, time)| DateTimeLiteral { date, time });
+
+    DateTimeTZLiteral => (any::<DateFragment>(), any::<TimeFragment>(), any::<TimeZone>())
+        .prop_map(|(date, time, timezone)| DateTimeTZLiteral { date, time, timezone });
+
+    DurationDate => any::<(Option<IntegerLiteral>, Option<IntegerLiteral>, Option<IntegerLiteral>)>()
+        .prop_filter("a duration date has at least one component", |(years, months, days)| {
+            years.is_some() || months.is_some() || days.is_some()
+        })
+        .prop_map(|(years, months, days)| DurationDate { years, months, days });
+
+    DurationTime => any::<(Option<IntegerLiteral>, Option<IntegerLiteral>, Option<NumericLiteral>)>()
+        .prop_filter("a duration time has at least one component", |(hours, minutes, seconds)| {
+            hours.is_some() || minutes.is_some() || seconds.is_some()
+        })
+        .prop_map(|(hours, minutes, seconds)| DurationTime { hours, minutes, seconds });
+
+    DurationLiteral => prop_oneof![
+        any::<IntegerLiteral>().prop_map(DurationLiteral::Weeks),
+        any::<(DurationDate, Option<DurationTime>)>().prop_map(|(date, time)| DurationLiteral::DateAndTime(date, time)),
+    ];
+}
+
+/// The content of a quoted string, using only the escape sequences that `StringLiteral::unescape` accepts.
+/// `quote_escape` is the escape sequence for the enclosing quote character.
+fn string_content(quote_escape: &'static str) -> impl Strategy<Value = String> {
+    let segment = prop_oneof![
+        "[^\"'\\\\]",
+        select(vec![r"\\", r"\b", r"\t", r"\n", r"\f", r"\r"]).prop_map(str::to_owned),
+        Just(quote_escape.to_owned()),
+    ];
+    proptest::collection::vec(segment, 0..16).prop_map(|segments| segments.concat())
+}
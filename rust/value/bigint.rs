@@ -0,0 +1,38 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions of integer literals of any size to `num_bigint::BigInt`, for values beyond the range of the primitive
+//! integer types.
+
+use num_bigint::{BigInt, Sign as BigIntSign};
+
+use super::{IntegerLiteral, Sign, SignedIntegerLiteral};
+
+impl IntegerLiteral {
+    /// The integer at full precision, however many digits it has.
+    pub fn as_bigint(&self) -> BigInt {
+        self.value.parse().expect("integer literals are digits")
+    }
+}
+
+impl SignedIntegerLiteral {
+    /// The integer at full precision, however many digits it has. `-0` is zero.
+    pub fn as_bigint(&self) -> BigInt {
+        let magnitude: BigInt = self.integral.parse().expect("integer literals are digits");
+        match self.sign {
+            Some(sign) => sign.apply(magnitude),
+            None => magnitude,
+        }
+    }
+}
+
+/// The canonical literal for the integer: no `+` sign or leading zeros.
+impl From<BigInt> for SignedIntegerLiteral {
+    fn from(integer: BigInt) -> Self {
+        let sign = (integer.sign() == BigIntSign::Minus).then_some(Sign::Minus);
+        SignedIntegerLiteral { sign, integral: integer.magnitude().to_string() }
+    }
+}
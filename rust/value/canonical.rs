@@ -0,0 +1,150 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::RangeInclusive;
+
+use super::{
+    ordering::Normalised, DateFragment, DurationDate, DurationLiteral, DurationTime, IntegerLiteral, Literal,
+    NumericLiteral, Sign, TimeFragment, TimeZone, ValueLiteral,
+};
+
+/// Canonical decimals `0.digits * 10^exponent` with an exponent in this range are written without scientific notation.
+const POSITIONAL_EXPONENTS: RangeInclusive<i64> = -5..=21;
+
+impl Literal {
+    /// The literal in strict canonical form, as used for equality keys or by a normalising formatter. Where `Display`
+    /// reproduces the source text, equal values of the same type have the same canonical text:
+    /// - numbers have no `+` sign, leading zeros or trailing fractional zeros, and decimals only use an exponent when
+    ///   very large or very small
+    /// - dates have zero-padded components, with the first month or day in place of any omitted from a partial date,
+    ///   and times always include seconds, with a fraction only when non-zero
+    /// - ISO time zone offsets are written `±HH:MM`, or `Z` for UTC
+    /// - booleans are lowercase
+    /// - ranges have canonical bounds
+    ///
+    /// Strings, IANA time zones, structs, nulls and decimals with an exponent beyond the range of an `i64` are kept as
+    /// written. The canonical text parses to an equal value.
+    pub fn to_canonical_string(&self) -> String {
+        self.inner.to_canonical_string()
+    }
+}
+
+impl ValueLiteral {
+    pub fn to_canonical_string(&self) -> String {
+        match self {
+            Self::Boolean(boolean) => boolean.value.to_lowercase(),
+            Self::Integer(integer) => canonical_integer(integer.sign, integer.magnitude()),
+            Self::Decimal(decimal) => match Normalised::new(decimal.sign, decimal.magnitude()) {
+                number if number.is_saturated() => decimal.to_string(),
+                number => canonical_decimal(&number),
+            },
+            Self::Date(date) => canonical_date(&date.date),
+            Self::DateTime(date_time) => {
+                format!("{}T{}", canonical_date(&date_time.date), canonical_time(&date_time.time))
+            }
+            Self::DateTimeTz(date_time) => format!(
+                "{}T{}{}",
+                canonical_date(&date_time.date),
+                canonical_time(&date_time.time),
+                canonical_timezone(&date_time.timezone)
+            ),
+            Self::Duration(duration) => canonical_duration(duration).to_string(),
+            Self::TimeZone(timezone) => canonical_timezone(timezone).trim_start().to_owned(),
+            Self::Range(range) => {
+                format!("{}{}{}", range.start.to_canonical_string(), range.separator(), range.end.to_canonical_string())
+            }
+            Self::String(_) | Self::Struct(_) | Self::Null(_) => self.to_string(),
+        }
+    }
+}
+
+fn canonical_integer(sign: Option<Sign>, digits: &str) -> String {
+    match digits.trim_start_matches('0') {
+        "" => "0".to_owned(),
+        digits if sign == Some(Sign::Minus) => format!("-{digits}"),
+        digits => digits.to_owned(),
+    }
+}
+
+fn canonical_decimal(number: &Normalised) -> String {
+    let Normalised { is_negative, digits, exponent } = number;
+    if digits.is_empty() {
+        return "0.0".to_owned();
+    }
+    let sign = if *is_negative { "-" } else { "" };
+    if !POSITIONAL_EXPONENTS.contains(exponent) {
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() { "0" } else { rest };
+        return format!("{sign}{first}.{rest}e{}", exponent.saturating_sub(1));
+    }
+    match usize::try_from(*exponent) {
+        Err(_) | Ok(0) => format!("{sign}0.{}{digits}", "0".repeat(exponent.unsigned_abs() as usize)),
+        Ok(point) if point >= digits.len() => format!("{sign}{digits}{}.0", "0".repeat(point - digits.len())),
+        Ok(point) => {
+            let (integral, fraction) = digits.split_at(point);
+            format!("{sign}{integral}.{fraction}")
+        }
+    }
+}
+
+fn canonical_date(date: &DateFragment) -> String {
+    let year = match date.year.parse::<i64>() {
+        Ok(year @ 0..=9999) => format!("{year:04}"),
+        Ok(year) => format!("{year:+05}"),
+        Err(_) => date.year.clone(),
+    };
+    format!("{year}-{}-{}", date.month_or_first(), date.day_or_first())
+}
+
+fn canonical_time(time: &TimeFragment) -> String {
+    let second = time.second.as_deref().unwrap_or("00");
+    match time.normalized_fraction() {
+        None => format!("{}:{}:{second}", time.hour, time.minute),
+        Some(fraction) => format!("{}:{}:{second}.{fraction}", time.hour, time.minute),
+    }
+}
+
+fn canonical_timezone(timezone: &TimeZone) -> String {
+    match (timezone, timezone.iso_offset_seconds()) {
+        (TimeZone::IANA(name), _) => format!(" {name}"),
+        (TimeZone::Utc, _) | (TimeZone::ISO(_), Some(0)) => "Z".to_owned(),
+        (TimeZone::ISO(_), Some(seconds)) => {
+            let sign = if seconds < 0 { '-' } else { '+' };
+            let minutes = seconds.unsigned_abs() / 60;
+            format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+        }
+        (TimeZone::ISO(offset), None) => offset.clone(),
+    }
+}
+
+fn canonical_duration(duration: &DurationLiteral) -> DurationLiteral {
+    let integer = |integer: &IntegerLiteral| IntegerLiteral { value: canonical_integer(None, &integer.value) };
+    match duration {
+        DurationLiteral::Weeks(weeks) => DurationLiteral::Weeks(integer(weeks)),
+        DurationLiteral::DateAndTime(date, time) => DurationLiteral::DateAndTime(
+            DurationDate {
+                years: date.years.as_ref().map(integer),
+                months: date.months.as_ref().map(integer),
+                days: date.days.as_ref().map(integer),
+            },
+            time.as_ref().map(|time| DurationTime {
+                hours: time.hours.as_ref().map(integer),
+                minutes: time.minutes.as_ref().map(integer),
+                seconds: time.seconds.as_ref().map(canonical_seconds),
+            }),
+        ),
+    }
+}
+
+fn canonical_seconds(seconds: &NumericLiteral) -> NumericLiteral {
+    let (integral, fraction) = seconds.value.split_once('.').unwrap_or((&seconds.value, ""));
+    let integral = canonical_integer(None, integral);
+    let value = match fraction.trim_end_matches('0') {
+        "" => integral,
+        fraction => format!("{integral}.{fraction}"),
+    };
+    NumericLiteral { value }
+}
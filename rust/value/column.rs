@@ -0,0 +1,45 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversion of a column of same-typed literals, such as a parsed CSV column, into a vector of native values. The
+//! first literal that is of another type or out of range is reported with its index in the column.
+
+use chrono::NaiveDate;
+
+use crate::{
+    common::{error::TypeQLError, Spanned},
+    value::{Literal, ValueType},
+    Result,
+};
+
+/// The integer literals as `i64`s. Integers outside the range of an `i64` are rejected.
+pub fn collect_integers(literals: &[Literal]) -> Result<Vec<i64>> {
+    collect(literals, ValueType::Integer, |literal| literal.as_i64().ok())
+}
+
+/// The decimal literals as the nearest `f64`s, as [`as_f64_lossy`](crate::value::SignedDecimalLiteral::as_f64_lossy)
+/// converts them. Decimals too large for a float are rejected rather than made infinite.
+pub fn collect_decimals(literals: &[Literal]) -> Result<Vec<f64>> {
+    collect(literals, ValueType::Decimal, |literal| literal.as_f64().ok())
+}
+
+/// The date literals as chrono dates. Dates that do not exist, such as `2023-02-29`, are rejected.
+pub fn collect_dates(literals: &[Literal]) -> Result<Vec<NaiveDate>> {
+    collect(literals, ValueType::Date, |literal| literal.as_date()?.date.as_naive_date().ok())
+}
+
+fn collect<T>(literals: &[Literal], expected: ValueType, convert: impl Fn(&Literal) -> Option<T>) -> Result<Vec<T>> {
+    literals
+        .iter()
+        .enumerate()
+        .map(|(index, literal)| {
+            convert(literal).ok_or_else(|| {
+                let (expected, literal, span) = (expected.to_string(), literal.to_string(), literal.span());
+                TypeQLError::InvalidColumnValue { index, expected, literal, span }.into()
+            })
+        })
+        .collect()
+}
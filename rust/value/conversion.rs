@@ -0,0 +1,303 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#[cfg(feature = "chrono-tz")]
+use std::cmp::Ordering;
+
+#[cfg(feature = "chrono-tz")]
+use chrono::{DateTime, LocalResult, TimeZone as _, Utc};
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+use crate::{
+    common::error::TypeQLError,
+    value::{
+        validation::{check_component, days_in_month},
+        DateFragment, DateTimeLiteral, DateTimeTZLiteral, TimeFragment, TimeZone,
+    },
+    Result,
+};
+
+impl DateFragment {
+    pub fn as_naive_date(&self) -> Result<NaiveDate> {
+        let invalid = || TypeQLError::InvalidDate { date: self.to_string() };
+        let year = self.year.parse().map_err(|_| invalid())?;
+        let month = self.month_or_first().parse().map_err(|_| invalid())?;
+        let day = self.day_or_first().parse().map_err(|_| invalid())?;
+        Ok(NaiveDate::from_ymd_opt(year, month, day).ok_or_else(invalid)?)
+    }
+
+    pub fn weekday(&self) -> Result<Weekday> {
+        Ok(self.as_naive_date()?.weekday())
+    }
+
+    /// The ordinal day of the year, from `1` to `366`.
+    pub fn day_of_year(&self) -> Result<u32> {
+        Ok(self.as_naive_date()?.ordinal())
+    }
+
+    /// The calendar date of an ISO 8601 week date, with `weekday` from `1` (Monday) to `7` (Sunday), or `None` if the
+    /// week year has no such week or day.
+    pub fn from_iso_week_date(week_year: i32, week: u32, weekday: u32) -> Option<DateFragment> {
+        let weekday = Weekday::try_from(u8::try_from(weekday.checked_sub(1)?).ok()?).ok()?;
+        Some(NaiveDate::from_isoywd_opt(week_year, week, weekday)?.into())
+    }
+
+    /// The ISO 8601 ordinal date `YYYY-DDD`, with the year as written.
+    pub fn to_ordinal_string(&self) -> Result<String> {
+        Ok(format!("{}-{:03}", self.year, self.day_of_year()?))
+    }
+
+    /// The calendar date of an ISO 8601 ordinal date, or `None` if `day` is outside the days of the year.
+    pub fn from_ordinal_date(year: i32, day: u32) -> Option<DateFragment> {
+        Some(NaiveDate::from_yo_opt(year, day)?.into())
+    }
+
+    /// A short English phrase for this date relative to `now`, for display: `today`, `yesterday`, `tomorrow`,
+    /// `N days ago` or `in N days`.
+    pub fn humanize_relative(&self, now: &DateFragment) -> Result<String> {
+        Ok(match (self.as_naive_date()? - now.as_naive_date()?).num_days() {
+            0 => "today".to_owned(),
+            -1 => "yesterday".to_owned(),
+            1 => "tomorrow".to_owned(),
+            days if days < 0 => format!("{} days ago", -days),
+            days => format!("in {days} days"),
+        })
+    }
+}
+
+impl From<NaiveDate> for DateFragment {
+    fn from(date: NaiveDate) -> Self {
+        let year = match date.year() {
+            year @ 0..=9999 => format!("{year:04}"),
+            year => format!("{year:+05}"),
+        };
+        DateFragment { year, month: Some(format!("{:02}", date.month())), day: Some(format!("{:02}", date.day())) }
+    }
+}
+
+impl From<NaiveTime> for TimeFragment {
+    /// The time always has seconds, and a fraction only when non-zero. Chrono's leap second representation becomes
+    /// second `60`.
+    fn from(time: NaiveTime) -> Self {
+        let (second, nanos) = match time.nanosecond() {
+            nanos @ 1_000_000_000.. => (60, nanos - 1_000_000_000),
+            nanos => (time.second(), nanos),
+        };
+        let second_fraction = match format!("{nanos:09}").trim_end_matches('0') {
+            "" => None,
+            fraction => Some(fraction.to_owned()),
+        };
+        TimeFragment {
+            hour: format!("{:02}", time.hour()),
+            minute: format!("{:02}", time.minute()),
+            second: Some(format!("{second:02}")),
+            second_fraction,
+        }
+    }
+}
+
+impl From<NaiveDateTime> for DateTimeLiteral {
+    fn from(date_time: NaiveDateTime) -> Self {
+        DateTimeLiteral { date: date_time.date().into(), time: date_time.time().into() }
+    }
+}
+
+impl TimeFragment {
+    /// A leap second is mapped onto chrono's leap second representation (second `59` with a nanosecond value
+    /// of at least `1_000_000_000`) rather than clamped, so that it still orders after `59.999999999`.
+    pub fn as_naive_time(&self) -> Result<NaiveTime> {
+        let invalid = || TypeQLError::InvalidTime { time: self.to_string() };
+        let (hour, minute) = self.checked_hour_and_minute()?;
+        let second = match &self.second {
+            Some(second) => second.parse().map_err(|_| invalid())?,
+            None => 0,
+        };
+        let nanos = self.fraction_nanos().unwrap_or(0);
+        let time = if second == 60 {
+            NaiveTime::from_hms_nano_opt(hour, minute, 59, 1_000_000_000 + nanos)
+        } else {
+            NaiveTime::from_hms_nano_opt(hour, minute, second, nanos)
+        };
+        Ok(time.ok_or_else(invalid)?)
+    }
+
+    /// The hour and minute, rejected with [`ComponentOutOfRange`](TypeQLError::ComponentOutOfRange) from hour `24`
+    /// or minute `60`, so that they are never carried into the next day or hour.
+    pub(super) fn checked_hour_and_minute(&self) -> Result<(u32, u32)> {
+        let invalid = || TypeQLError::InvalidTime { time: self.to_string() };
+        let hour = check_component("hour", self.hour.parse().map_err(|_| invalid())?, 0..=23, None)?;
+        let minute = check_component("minute", self.minute.parse().map_err(|_| invalid())?, 0..=59, None)?;
+        Ok((hour, minute))
+    }
+}
+
+impl DateTimeLiteral {
+    pub fn as_naive_datetime(&self) -> Result<NaiveDateTime> {
+        Ok(NaiveDateTime::new(self.date.as_naive_date()?, self.time.as_naive_time()?))
+    }
+}
+
+impl DateTimeTZLiteral {
+    /// A datetime-tz from its components, rejecting any component out of range with
+    /// [`ComponentOutOfRange`](TypeQLError::ComponentOutOfRange), so that the literal always displays as valid TypeQL.
+    /// The day must exist in the month, and leap seconds are rejected, as they are by default validation. The seconds
+    /// are omitted when `second` and `nanos` are `None`, and written as `00` when only `nanos` is given; a zero
+    /// fraction is omitted. An ISO offset must be a valid offset, and, with the `chrono-tz` feature, an IANA time zone
+    /// must be known. `TimeZone::ISO("Z")` is taken as [`TimeZone::Utc`], which is how a parsed `Z` is stored.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: Option<u32>,
+        nanos: Option<u32>,
+        timezone: TimeZone,
+    ) -> Result<Self> {
+        check_component("year", year, NaiveDate::MIN.year()..=NaiveDate::MAX.year(), None)?;
+        let month = check_component("month", month, 1..=12, None)?;
+        let day = check_component("day", day, 1..=days_in_month(year.into(), month), None)?;
+        let date = NaiveDate::from_ymd_opt(year, month, day).expect("the date components are in range");
+        check_component("hour", hour, 0..=23, None)?;
+        check_component("minute", minute, 0..=59, None)?;
+        if let Some(second) = second {
+            check_component("second", second, 0..=59, None)?;
+        }
+        let second_fraction = match nanos {
+            Some(nanos) => {
+                check_component("nanosecond", nanos, 0..=999_999_999, None)?;
+                Some(format!("{nanos:09}").trim_end_matches('0').to_owned()).filter(|digits| !digits.is_empty())
+            }
+            None => None,
+        };
+        let time = TimeFragment {
+            hour: format!("{hour:02}"),
+            minute: format!("{minute:02}"),
+            second: second.or(nanos.map(|_| 0)).map(|second| format!("{second:02}")),
+            second_fraction,
+        };
+        let timezone = match timezone {
+            TimeZone::ISO(offset) if offset == "Z" => TimeZone::Utc,
+            timezone => timezone,
+        };
+        let literal = DateTimeTZLiteral { date: date.into(), time, timezone };
+        // the other components are checked, so a literal that does not parse back has a malformed time zone
+        let reparses = literal.to_string().parse::<DateTimeTZLiteral>().is_ok_and(|reparsed| reparsed == literal);
+        match &literal.timezone {
+            TimeZone::ISO(offset)
+                if !reparses || literal.timezone.iso_offset_seconds().and_then(FixedOffset::east_opt).is_none() =>
+            {
+                Err(TypeQLError::InvalidTimeZoneOffset { offset: offset.clone() })?
+            }
+            TimeZone::IANA(name) if !reparses => Err(TypeQLError::UnknownTimeZone { name: name.clone() })?,
+            #[cfg(feature = "chrono-tz")]
+            TimeZone::IANA(name) if name.parse::<chrono_tz::Tz>().is_err() => {
+                Err(TypeQLError::UnknownTimeZone { name: name.clone() })?
+            }
+            _ => Ok(literal),
+        }
+    }
+
+    /// The local date and time, disregarding the time zone.
+    pub fn as_naive_datetime(&self) -> Result<NaiveDateTime> {
+        Ok(NaiveDateTime::new(self.date.as_naive_date()?, self.time.as_naive_time()?))
+    }
+
+    /// The UTC datetime `seconds` after the Unix epoch, or before it if negative, with the seconds always written.
+    /// `None` if the instant is outside the range of years chrono supports.
+    pub fn from_unix_timestamp(seconds: i64) -> Option<DateTimeTZLiteral> {
+        let date_time = chrono::DateTime::from_timestamp(seconds, 0)?.naive_utc();
+        Some(DateTimeLiteral::from(date_time).with_timezone(TimeZone::Utc))
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+impl DateTimeTZLiteral {
+    /// The instant this literal denotes. A local time that is ambiguous or skipped due to a daylight saving
+    /// transition in the literal's time zone is an error, rather than being resolved by guessing.
+    pub fn as_utc_datetime(&self) -> Result<DateTime<Utc>> {
+        let local = self.as_naive_datetime()?;
+        let resolved = match &self.timezone {
+            TimeZone::IANA(name) => {
+                let zone: chrono_tz::Tz =
+                    name.parse().map_err(|_| TypeQLError::UnknownTimeZone { name: name.clone() })?;
+                zone.from_local_datetime(&local).map(|date_time| date_time.with_timezone(&Utc))
+            }
+            TimeZone::ISO(offset) => {
+                let invalid = || TypeQLError::InvalidTimeZoneOffset { offset: offset.clone() };
+                let seconds = self.timezone.iso_offset_seconds().ok_or_else(invalid)?;
+                let zone = FixedOffset::east_opt(seconds).ok_or_else(invalid)?;
+                zone.from_local_datetime(&local).map(|date_time| date_time.with_timezone(&Utc))
+            }
+            TimeZone::Utc => Utc.from_local_datetime(&local),
+        };
+        match resolved {
+            LocalResult::Single(date_time) => Ok(date_time),
+            LocalResult::Ambiguous(_, _) => Err(TypeQLError::AmbiguousLocalTime {
+                date_time: local.to_string(),
+                time_zone: self.timezone.to_string(),
+            })?,
+            LocalResult::None => Err(TypeQLError::NonexistentLocalTime {
+                date_time: local.to_string(),
+                time_zone: self.timezone.to_string(),
+            })?,
+        }
+    }
+
+    /// Orders two literals by the instants they denote, so `2024-01-01T10:00+02:00` is before `2024-01-01T09:00Z`,
+    /// unlike the zone-naive `Ord`. Fails as [`as_utc_datetime`](Self::as_utc_datetime) does when either instant
+    /// cannot be resolved.
+    pub fn cmp_instant(&self, other: &Self) -> Result<Ordering> {
+        Ok(self.as_utc_datetime()?.cmp(&other.as_utc_datetime()?))
+    }
+
+    /// Seconds since the Unix epoch, and the nanoseconds within that second, of the instant this literal denotes.
+    pub fn to_unix_timestamp(&self) -> Result<(i64, u32)> {
+        let instant = self.as_utc_datetime()?;
+        Ok((instant.timestamp(), instant.timestamp_subsec_nanos()))
+    }
+
+    /// The instant this literal denotes, written in UTC with millisecond precision: `YYYY-MM-DDThh:mm:ss.fffZ`.
+    /// Unlike [`to_canonical_string`](crate::value::Literal::to_canonical_string), which keeps the time zone, the
+    /// same instant written in different time zones gives the same text.
+    pub fn to_utc_string(&self) -> Result<String> {
+        Ok(self.as_utc_datetime()?.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string())
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+impl TimeZone {
+    /// The ISO offset an IANA time zone has at the local datetime `at`, e.g. `+01:00` for `Europe/London` in summer,
+    /// to store a datetime with a fixed offset. ISO offsets and UTC are already fixed, so are returned unchanged. There
+    /// is no inverse, as many IANA zones share each offset. An offset with seconds, as some historical local mean times
+    /// have, cannot be written as an ISO offset literal and is an error.
+    pub fn to_fixed_offset(&self, at: &DateTimeLiteral) -> Result<TimeZone> {
+        if !matches!(self, TimeZone::IANA(_)) {
+            return Ok(self.clone());
+        }
+        let seconds = self.offset_seconds_at(at)?;
+        let sign = if seconds < 0 { '-' } else { '+' };
+        let (minutes, seconds) = (seconds.unsigned_abs() / 60, seconds.unsigned_abs() % 60);
+        let offset = format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60);
+        if seconds != 0 {
+            Err(TypeQLError::InvalidTimeZoneOffset { offset: format!("{offset}:{seconds:02}") })?
+        }
+        Ok(TimeZone::ISO(offset))
+    }
+
+    /// Whether both time zones have the same offset at the local datetime `at`, e.g. to group zones that are
+    /// currently equivalent. Unlike `==`, which compares how the zones are written, `America/New_York` resolves the
+    /// same as `-05:00` in winter, but not in summer, when it is `-04:00`.
+    pub fn resolves_same_as(&self, other: &TimeZone, at: &DateTimeLiteral) -> Result<bool> {
+        Ok(self.offset_seconds_at(at)? == other.offset_seconds_at(at)?)
+    }
+
+    fn offset_seconds_at(&self, at: &DateTimeLiteral) -> Result<i64> {
+        let instant = at.assume_timezone(self.clone()).as_utc_datetime()?;
+        Ok((at.as_naive_datetime()? - instant.naive_utc()).num_seconds())
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::{
+    common::error::TypeQLError,
+    value::{
+        validation::{check_component, days_in_month},
+        DateFragment,
+    },
+    Result,
+};
+
+/// A year from 0 to 9999, the years that can be written with four digits.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Year(u16);
+
+/// A month from 1 to 12.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Month(u8);
+
+/// A day of the month from 1 to 31. Whether the day exists in a given month is checked by [`DateFragment::parts`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Day(u8);
+
+macro_rules! impl_date_part {
+    ($($type:ident($repr:ty) in $range:expr, $part:literal;)*) => {$(
+        impl $type {
+            pub fn new(value: $repr) -> Result<Self> {
+                Ok(Self(check_component($part, value, $range, None)?))
+            }
+
+            pub fn get(self) -> $repr {
+                self.0
+            }
+        }
+    )*};
+}
+
+impl_date_part! {
+    Year(u16) in 0..=9999, "year";
+    Month(u8) in 1..=12, "month";
+    Day(u8) in 1..=31, "day";
+}
+
+impl DateFragment {
+    /// The year, month and day of the date, checked to form a calendar date. The month and day omitted from a partial
+    /// date are the first.
+    pub fn parts(&self) -> Result<(Year, Month, Day)> {
+        let invalid = || TypeQLError::InvalidDate { date: self.to_string() };
+        let year = check_component("year", self.year.parse().map_err(|_| invalid())?, 0..=9999, None)?;
+        let month = Month::new(self.month_or_first().parse().map_err(|_| invalid())?)?;
+        let last_day = days_in_month(year, month.get().into());
+        let day = check_component("day", self.day_or_first().parse().map_err(|_| invalid())?, 1..=last_day, None)?;
+        Ok((Year(year as u16), month, Day(day as u8)))
+    }
+}
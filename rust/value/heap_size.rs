@@ -0,0 +1,111 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use super::{
+    DateFragment, DurationDate, DurationLiteral, DurationTime, IntegerLiteral, Literal, NumericLiteral, TimeFragment,
+    TimeZone, ValueLiteral,
+};
+
+impl Literal {
+    /// The bytes the literal's owned strings and boxed range bounds allocate on the heap, by capacity rather than
+    /// length, e.g. to bound a cache by the memory of its entries. The span is stored inline, so takes no heap space;
+    /// add `size_of::<Literal>()` for the full footprint.
+    pub fn heap_size(&self) -> usize {
+        self.original.heap_size() + self.inner.heap_size()
+    }
+}
+
+trait HeapSize {
+    fn heap_size(&self) -> usize;
+}
+
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, HeapSize::heap_size)
+    }
+}
+
+impl HeapSize for ValueLiteral {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Boolean(boolean) => boolean.value.heap_size(),
+            Self::Integer(integer) => integer.integral.heap_size(),
+            Self::Decimal(decimal) => decimal.decimal.heap_size(),
+            Self::Date(date) => date.date.heap_size(),
+            Self::DateTime(date_time) => date_time.date.heap_size() + date_time.time.heap_size(),
+            Self::DateTimeTz(date_time) => {
+                date_time.date.heap_size() + date_time.time.heap_size() + date_time.timezone.heap_size()
+            }
+            Self::Duration(duration) => duration.heap_size(),
+            Self::String(string) => string.value.heap_size(),
+            Self::Struct(struct_) => struct_.inner.heap_size(),
+            Self::Null(null) => null.value.heap_size(),
+            Self::TimeZone(timezone) => timezone.heap_size(),
+            Self::Range(range) => 2 * size_of::<Literal>() + range.start.heap_size() + range.end.heap_size(),
+        }
+    }
+}
+
+impl HeapSize for DateFragment {
+    fn heap_size(&self) -> usize {
+        self.year.heap_size() + self.month.heap_size() + self.day.heap_size()
+    }
+}
+
+impl HeapSize for TimeFragment {
+    fn heap_size(&self) -> usize {
+        self.hour.heap_size() + self.minute.heap_size() + self.second.heap_size() + self.second_fraction.heap_size()
+    }
+}
+
+impl HeapSize for TimeZone {
+    fn heap_size(&self) -> usize {
+        match self {
+            TimeZone::IANA(name) => name.heap_size(),
+            TimeZone::ISO(offset) => offset.heap_size(),
+            TimeZone::Utc => 0,
+        }
+    }
+}
+
+impl HeapSize for DurationLiteral {
+    fn heap_size(&self) -> usize {
+        match self {
+            DurationLiteral::Weeks(weeks) => weeks.heap_size(),
+            DurationLiteral::DateAndTime(date, time) => date.heap_size() + time.heap_size(),
+        }
+    }
+}
+
+impl HeapSize for DurationDate {
+    fn heap_size(&self) -> usize {
+        self.years.heap_size() + self.months.heap_size() + self.days.heap_size()
+    }
+}
+
+impl HeapSize for DurationTime {
+    fn heap_size(&self) -> usize {
+        self.hours.heap_size() + self.minutes.heap_size() + self.seconds.heap_size()
+    }
+}
+
+impl HeapSize for IntegerLiteral {
+    fn heap_size(&self) -> usize {
+        self.value.heap_size()
+    }
+}
+
+impl HeapSize for NumericLiteral {
+    fn heap_size(&self) -> usize {
+        self.value.heap_size()
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions of JSON scalars to literals, for ingesting JSON documents as attribute values.
+
+use serde_json::{Number, Value};
+
+use super::{
+    BooleanLiteral, EscapePolicy, Literal, Sign, SignedDecimalLiteral, SignedIntegerLiteral, StringLiteral,
+    ValueLiteral,
+};
+use crate::{common::error::TypeQLError, Result};
+
+impl Literal {
+    /// The literal for a JSON scalar: a string, a boolean, or a number, which is an integer when `serde_json` writes it
+    /// without a fractional part or exponent, and a decimal otherwise. JSON nulls, arrays and objects are rejected with
+    /// [`UnsupportedJsonValue`](TypeQLError::UnsupportedJsonValue).
+    pub fn from_json(value: &Value) -> Result<Literal> {
+        let inner = match value {
+            Value::Bool(value) => ValueLiteral::Boolean(BooleanLiteral { value: value.to_string() }),
+            Value::Number(number) => json_number(number)?,
+            Value::String(content) => ValueLiteral::String(StringLiteral::build(content, EscapePolicy::Minimal)),
+            Value::Null => Err(TypeQLError::UnsupportedJsonValue { kind: "null".to_owned() })?,
+            Value::Array(_) => Err(TypeQLError::UnsupportedJsonValue { kind: "array".to_owned() })?,
+            Value::Object(_) => Err(TypeQLError::UnsupportedJsonValue { kind: "object".to_owned() })?,
+        };
+        Ok(Literal::new(None, inner))
+    }
+}
+
+fn json_number(number: &Number) -> Result<ValueLiteral> {
+    // decided from the text rather than `as_f64`, which is absent for large numbers under `arbitrary_precision`
+    let text = number.to_string();
+    let (sign, magnitude) = match text.strip_prefix('-') {
+        Some(magnitude) => (Some(Sign::Minus), magnitude),
+        None => (None, text.as_str()),
+    };
+    let (mantissa, exponent) = match magnitude.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (magnitude, None),
+    };
+    let (integral, fraction) = match mantissa.split_once('.') {
+        Some((integral, fraction)) => (integral, Some(fraction)),
+        None => (mantissa, None),
+    };
+    let is_digits = |digits: &str| !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit());
+    let is_exponent = |exponent: &str| is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent));
+    if !is_digits(integral) || !fraction.map_or(true, is_digits) || !exponent.map_or(true, is_exponent) {
+        Err(TypeQLError::UnsupportedJsonValue { kind: format!("number written as '{text}'") })?
+    }
+    if fraction.is_none() && exponent.is_none() {
+        return Ok(ValueLiteral::Integer(SignedIntegerLiteral { sign, integral: integral.to_owned() }));
+    }
+    let mut decimal = format!("{integral}.{}", fraction.unwrap_or("0"));
+    if let Some(exponent) = exponent {
+        decimal.push('e');
+        decimal.push_str(exponent);
+    }
+    Ok(ValueLiteral::Decimal(SignedDecimalLiteral { sign, decimal }))
+}
@@ -0,0 +1,1247 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::{
+    fmt::{self, Formatter},
+    ops::Neg,
+};
+
+use self::ordering::Normalised;
+pub use self::{
+    column::{collect_dates, collect_decimals, collect_integers},
+    date_parts::{Day, Month, Year},
+    sql::SqlDialect,
+    validation::ValidationOptions,
+};
+use crate::{
+    common::{error::TypeQLError, LineColumn, Span, Spanned},
+    pretty::Pretty,
+    Result,
+};
+
+#[cfg(feature = "proptest")]
+mod arbitrary;
+#[cfg(feature = "num-bigint")]
+mod bigint;
+mod canonical;
+mod column;
+mod conversion;
+mod date_parts;
+mod heap_size;
+#[cfg(feature = "serde")]
+mod json;
+mod native;
+mod ordering;
+mod sql;
+#[cfg(feature = "time")]
+mod time;
+mod validation;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BooleanLiteral {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StringLiteral {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct IntegerLiteral {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NumericLiteral {
+    pub value: String,
+}
+
+/// How [`TimeFragment::fraction_nanos_with`] treats second fraction digits beyond nanosecond precision, and
+/// [`DateTimeLiteral::truncate_to_seconds`] the whole fraction.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum FractionPolicy {
+    #[default]
+    Truncate,
+    RoundHalfUp,
+}
+
+/// Which characters [`StringLiteral::build`] escapes beyond the quote and backslash.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum EscapePolicy {
+    /// Keep all other characters as UTF-8, for human-readable output.
+    #[default]
+    Minimal,
+    /// Escape every non-ASCII character as `\u{...}`, for output that must be ASCII.
+    AsciiOnly,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Sign {
+    Plus,
+    Minus,
+}
+
+/// An integer with its sign as written: `+5` keeps `Sign::Plus`, while `5` has no sign. Both denote the same value,
+/// but only the unsigned form is canonical.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SignedIntegerLiteral {
+    pub sign: Option<Sign>,
+    pub integral: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SignedDecimalLiteral {
+    pub sign: Option<Sign>,
+    pub decimal: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DateFragment {
+    pub year: String,
+    /// `None` only in a year-granular partial date, see [`ParserOptions::allow_partial_dates`].
+    ///
+    /// [`ParserOptions::allow_partial_dates`]: crate::parser::ParserOptions::allow_partial_dates
+    pub month: Option<String>,
+    /// `None` only in a partial date.
+    pub day: Option<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TimeFragment {
+    pub hour: String,
+    pub minute: String,
+    pub second: Option<String>,
+    pub second_fraction: Option<String>,
+}
+
+/// A datetime with a time zone, written with a space before an IANA zone name (`2024-01-01T10:00 Europe/London`)
+/// and with an ISO 8601 offset directly after the time (`2024-01-01T10:00+01:00`). The other delimiter, or brackets, are
+/// rejected with [`MisdelimitedTimeZone`](TypeQLError::MisdelimitedTimeZone).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DateTimeTZLiteral {
+    pub date: DateFragment,
+    pub time: TimeFragment,
+    pub timezone: TimeZone,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DateTimeLiteral {
+    pub date: DateFragment,
+    pub time: TimeFragment,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DateLiteral {
+    pub date: DateFragment,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum TimeZone {
+    IANA(String),
+    ISO(String),
+    /// The `Z` designator.
+    Utc,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DurationLiteral {
+    Weeks(IntegerLiteral),
+    DateAndTime(DurationDate, Option<DurationTime>),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StructLiteral {
+    pub inner: String, // TODO
+}
+
+/// A missing value, written as one of the [`null_tokens`](crate::parser::ParserOptions::null_tokens) of the parser
+/// options. The value is the token as written.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NullLiteral {
+    pub value: String,
+}
+
+/// A range between two literals of the same value type, written `start..end`, or `start..<end` to exclude the end.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RangeLiteral {
+    pub start: Box<Literal>,
+    pub end: Box<Literal>,
+    pub inclusive_end: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DurationDate {
+    pub years: Option<IntegerLiteral>,
+    pub months: Option<IntegerLiteral>,
+    pub days: Option<IntegerLiteral>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DurationTime {
+    pub hours: Option<IntegerLiteral>,
+    pub minutes: Option<IntegerLiteral>,
+    pub seconds: Option<NumericLiteral>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ValueLiteral {
+    Boolean(BooleanLiteral),
+    Integer(SignedIntegerLiteral),
+    Decimal(SignedDecimalLiteral),
+    Date(DateLiteral),
+    DateTime(DateTimeLiteral),
+    DateTimeTz(DateTimeTZLiteral),
+    Duration(DurationLiteral),
+    String(StringLiteral),
+    Struct(StructLiteral),
+    Null(NullLiteral),
+    TimeZone(TimeZone),
+    Range(RangeLiteral),
+}
+
+/// The value type of a literal, declared in the precedence used by [`Literal::total_cmp`]:
+/// Null < Boolean < Integer < Decimal < String < Date < DateTime < DateTimeTz < TimeZone < Duration < Range < Struct.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ValueType {
+    Null,
+    Boolean,
+    Integer,
+    Decimal,
+    String,
+    Date,
+    DateTime,
+    DateTimeTz,
+    TimeZone,
+    Duration,
+    Range,
+    Struct,
+}
+
+impl ValueType {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Integer => "integer",
+            Self::Decimal => "decimal",
+            Self::String => "string",
+            Self::Date => "date",
+            Self::DateTime => "datetime",
+            Self::DateTimeTz => "datetime-tz",
+            Self::TimeZone => "timezone",
+            Self::Duration => "duration",
+            Self::Range => "range",
+            Self::Struct => "struct",
+        }
+    }
+
+    /// Whether values of the type are points or spans in time: dates, datetimes, datetime-tzs and durations. A time
+    /// zone alone is not.
+    pub const fn is_temporal(&self) -> bool {
+        matches!(self, Self::Date | Self::DateTime | Self::DateTimeTz | Self::Duration)
+    }
+
+    /// Whether values of the type are numbers: integers and decimals.
+    pub const fn is_numeric(&self) -> bool {
+        matches!(self, Self::Integer | Self::Decimal)
+    }
+}
+
+impl ValueLiteral {
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Self::Boolean(_) => ValueType::Boolean,
+            Self::Integer(_) => ValueType::Integer,
+            Self::Decimal(_) => ValueType::Decimal,
+            Self::Date(_) => ValueType::Date,
+            Self::DateTime(_) => ValueType::DateTime,
+            Self::DateTimeTz(_) => ValueType::DateTimeTz,
+            Self::Duration(_) => ValueType::Duration,
+            Self::String(_) => ValueType::String,
+            Self::Struct(_) => ValueType::Struct,
+            Self::Null(_) => ValueType::Null,
+            Self::TimeZone(_) => ValueType::TimeZone,
+            Self::Range(_) => ValueType::Range,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Literal {
+    span: Option<Span>,
+    original: Option<String>,
+    pub inner: ValueLiteral,
+}
+
+impl Literal {
+    pub(crate) fn new(span: Option<Span>, inner: ValueLiteral) -> Self {
+        Self { span, original: None, inner }
+    }
+
+    pub(crate) fn with_original(self, original: String) -> Self {
+        Self { original: Some(original), ..self }
+    }
+
+    pub(crate) fn with_span(self, span: Option<Span>) -> Self {
+        Self { span, ..self }
+    }
+
+    /// The source text of a literal whose value was rewritten while parsing a non-canonical form,
+    /// e.g. `12%` for the decimal `0.12`. Canonical literals display as their source text, and have no original.
+    pub fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+
+    /// Whether writing the literal out, whether by `Display` or as its
+    /// [canonical string](Self::to_canonical_string), loses how it was written, e.g. to warn before a normalising
+    /// rewrite. Only literals rewritten from an [`original`](Self::original) text are lossy: `$1,234.50` is written
+    /// back as `1234.50`, whereas the canonical `7` of `007` still reparses to an equal value of the same type.
+    pub fn is_lossy(&self) -> bool {
+        self.original.is_some()
+    }
+
+    /// The radix an integer literal was written in, e.g. to re-emit a hexadecimal `0xFF` rather than the decoded `255`:
+    /// `16` or `2` for an integer parsed with
+    /// [`allow_radix_integers`](crate::parser::ParserOptions::allow_radix_integers), whose digits are in its
+    /// [`original`](Self::original), and `10` for any other integer. `None` for a literal of another type.
+    pub fn radix(&self) -> Option<u32> {
+        let ValueLiteral::Integer(_) = &self.inner else { return None };
+        // no other integer form's original starts with a radix prefix
+        match self.original.as_deref().map(|original| original.trim_start_matches(['+', '-']).get(..2)) {
+            Some(Some("0x")) => Some(16),
+            Some(Some("0b")) => Some(2),
+            _ => Some(10),
+        }
+    }
+
+    pub fn value_type(&self) -> ValueType {
+        self.inner.value_type()
+    }
+
+    /// The literal if it is of type `value_type`, e.g. to check a parsed value before inserting it into an attribute
+    /// of known type, and an error spanning the literal otherwise.
+    pub fn expect_type(&self, value_type: ValueType) -> Result<&Self> {
+        if self.value_type() != value_type {
+            let (expected, found) = (value_type.to_string(), self.value_type().to_string());
+            Err(TypeQLError::ValueTypeMismatch { expected, found, span: self.span })?
+        }
+        Ok(self)
+    }
+
+    /// Whether the literal is of a [temporal](ValueType::is_temporal) type.
+    pub fn is_temporal(&self) -> bool {
+        self.value_type().is_temporal()
+    }
+
+    /// The date of a date, datetime or datetime-tz literal, e.g. to group values by day. A datetime-tz has its local
+    /// date in its own time zone, not the date at UTC.
+    pub fn as_date_fragment(&self) -> Option<&DateFragment> {
+        match &self.inner {
+            ValueLiteral::Date(date) => Some(&date.date),
+            ValueLiteral::DateTime(date_time) => Some(&date_time.date),
+            ValueLiteral::DateTimeTz(date_time) => Some(&date_time.date),
+            _ => None,
+        }
+    }
+
+    /// Whether the literal is of a [numeric](ValueType::is_numeric) type.
+    pub fn is_numeric(&self) -> bool {
+        self.value_type().is_numeric()
+    }
+
+    /// Replaces the value, keeping the span so that the rewritten literal still points at its source position.
+    /// The [`original`](Self::original) text described the replaced value, so is dropped.
+    pub fn map_value(self, f: impl FnOnce(ValueLiteral) -> ValueLiteral) -> Literal {
+        Literal::new(self.span, f(self.inner))
+    }
+
+    /// Moves the literal's span, and those of a range's bounds, from the fragment it was parsed from to a larger input
+    /// in which the fragment begins at `origin`, e.g. when a tool embeds a separately parsed literal in a document.
+    /// Spans are lines and columns rather than byte offsets, so only the first line of the fragment shifts columns.
+    /// A span with a zero line or column, which no parsed literal has, is dropped.
+    pub fn reoffset(&mut self, origin: LineColumn) {
+        self.span = self.span.and_then(|span| span.offset_to(origin));
+        if let ValueLiteral::Range(range) = &mut self.inner {
+            range.start.reoffset(origin);
+            range.end.reoffset(origin);
+        }
+    }
+
+    /// The literal without its span, e.g. to cache it beyond the input it was parsed from. Literals own all their
+    /// text, so the span is the only tie to the input; without it, equal literals compare equal wherever they
+    /// appeared.
+    pub fn without_span(self) -> Literal {
+        self.with_span(None)
+    }
+
+    /// Whether both literals were written with exactly the same source text, wherever they appear.
+    ///
+    /// Literals can be compared in three ways, from strictest to loosest:
+    /// - structural equality (`==`) compares the source text and also the span, so is position dependent
+    /// - `raw_eq` compares the source text only, e.g. to check whether a literal changed between edits
+    /// - [`total_cmp`](Self::total_cmp) compares values, so `5` and `05`, or `1.5` and `1.50`, are equal
+    pub fn raw_eq(&self, other: &Literal) -> bool {
+        self.inner == other.inner && self.original == other.original
+    }
+
+    /// Checks that the literal's `Display` output parses back to an equal value, e.g. to test a new `Display`
+    /// implementation or assert the invariants of a builder. Intended for debugging and testing, not hot paths: it
+    /// formats and reparses the literal.
+    ///
+    /// Only forms accepted by [`parse_value_literal`](crate::parse_value_literal) can round trip, so null and struct
+    /// literals always fail, and so do partial dates whose text is also an integer.
+    pub fn verify_roundtrip(&self) -> Result {
+        let literal = self.to_string();
+        let reparsed = crate::parse_value_literal(&literal)?;
+        if reparsed.inner != self.inner {
+            Err(TypeQLError::RoundTripMismatch { literal, reparsed: format!("{:?}", reparsed.inner) })?
+        }
+        Ok(())
+    }
+
+    /// The number of characters in the literal's `Display` output, including any quotes and escapes, e.g. to lay out
+    /// a table without rendering each literal first.
+    pub fn display_width(&self) -> usize {
+        let mut count = CharCount(0);
+        fmt::Write::write_fmt(&mut count, format_args!("{self}")).expect("counting characters cannot fail");
+        count.0
+    }
+
+    /// The span of the literal followed by the spans of its date, time and time zone fragments, or of its bounds if it
+    /// is a range, e.g. for semantic highlighting. Fragments are not spanned while parsing, so their spans are located
+    /// within the literal's span from the length of their text. A literal without a span has no spans, and a literal
+    /// rewritten from its [`original`](Self::original) text only has its own span.
+    pub fn all_spans(&self) -> Vec<Span> {
+        let Some(span) = self.span else { return Vec::new() };
+        if self.original.is_some() {
+            return vec![span];
+        }
+        let (date, time, timezone) = match &self.inner {
+            ValueLiteral::Date(date) => (&date.date, None, None),
+            ValueLiteral::DateTime(date_time) => (&date_time.date, Some(&date_time.time), None),
+            ValueLiteral::DateTimeTz(date_time) => (&date_time.date, Some(&date_time.time), Some(&date_time.timezone)),
+            ValueLiteral::Range(range) => {
+                return [Some(span), range.start.span, range.end.span].into_iter().flatten().collect()
+            }
+            _ => return vec![span],
+        };
+        let mut spans = vec![span];
+        let mut offset = span.begin.column;
+        let mut push_fragment = |separator: &str, text: String| {
+            let begin = offset + separator.len() as u32;
+            offset = begin + text.len() as u32;
+            let line = span.begin.line;
+            spans.push(Span { begin: LineColumn { line, column: begin }, end: LineColumn { line, column: offset } });
+        };
+        push_fragment("", date.to_string());
+        if let Some(time) = time {
+            push_fragment("T", time.to_string());
+        }
+        match timezone {
+            Some(TimeZone::IANA(name)) => push_fragment(" ", name.clone()),
+            Some(timezone) => push_fragment("", timezone.to_string()),
+            None => (),
+        }
+        spans
+    }
+}
+
+/// Implements `as_<type>` and `into_<type>` on `Literal`, returning the value if it is of that type.
+macro_rules! impl_literal_downcasts {
+    ($($variant:ident($type:ty) => $as:ident, $into:ident;)*) => {
+        impl Literal {$(
+            #[doc = concat!("The value if it is `ValueLiteral::", stringify!($variant), "`.")]
+            pub fn $as(&self) -> Option<&$type> {
+                match &self.inner {
+                    ValueLiteral::$variant(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            #[doc = concat!("The owned value if it is `ValueLiteral::", stringify!($variant), "`.")]
+            pub fn $into(self) -> Option<$type> {
+                match self.inner {
+                    ValueLiteral::$variant(value) => Some(value),
+                    _ => None,
+                }
+            }
+        )*}
+    };
+}
+
+impl_literal_downcasts! {
+    Boolean(BooleanLiteral) => as_boolean, into_boolean;
+    Integer(SignedIntegerLiteral) => as_integer, into_integer;
+    Decimal(SignedDecimalLiteral) => as_decimal, into_decimal;
+    Date(DateLiteral) => as_date, into_date;
+    DateTime(DateTimeLiteral) => as_datetime, into_datetime;
+    DateTimeTz(DateTimeTZLiteral) => as_datetime_tz, into_datetime_tz;
+    Duration(DurationLiteral) => as_duration, into_duration;
+    String(StringLiteral) => as_string, into_string;
+    TimeZone(TimeZone) => as_timezone, into_timezone;
+    Range(RangeLiteral) => as_range, into_range;
+}
+
+impl Spanned for Literal {
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+impl Pretty for Literal {}
+
+/// Counts the characters written to it, to measure `Display` output without allocating it.
+struct CharCount(usize);
+
+impl fmt::Write for CharCount {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.0 += string.chars().count();
+        Ok(())
+    }
+}
+
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for ValueLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueLiteral::Boolean(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::Integer(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::Decimal(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::Date(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::DateTime(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::DateTimeTz(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::Duration(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::String(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::Struct(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::Null(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::TimeZone(value) => fmt::Display::fmt(value, f),
+            ValueLiteral::Range(value) => fmt::Display::fmt(value, f),
+        }
+    }
+}
+
+impl fmt::Display for IntegerLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.value.as_str())
+    }
+}
+
+impl fmt::Display for NumericLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.value.as_str())
+    }
+}
+
+impl fmt::Display for StringLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.value.as_str())
+    }
+}
+
+impl fmt::Display for Sign {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Sign::Plus => f.write_str("+"),
+            Sign::Minus => f.write_str("-"),
+        }
+    }
+}
+
+impl fmt::Display for DateFragment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.year)?;
+        if let Some(month) = &self.month {
+            write!(f, "-{month}")?;
+        }
+        if let Some(day) = &self.day {
+            write!(f, "-{day}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TimeFragment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (hour, minute) = (self.hour.as_str(), self.minute.as_str());
+        match &self.second {
+            None => write!(f, "{hour}:{minute}"),
+            Some(second) => match &self.second_fraction {
+                None => write!(f, "{hour}:{minute}:{second}"),
+                Some(second_fraction) => write!(f, "{hour}:{minute}:{second}.{second_fraction}"),
+            },
+        }
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeZone::IANA(value) => f.write_str(value),
+            TimeZone::ISO(value) => f.write_str(value),
+            TimeZone::Utc => f.write_str("Z"),
+        }
+    }
+}
+
+impl fmt::Display for DurationDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(years) = &self.years {
+            write!(f, "{years}Y")?;
+        }
+        if let Some(months) = &self.months {
+            write!(f, "{months}M")?;
+        }
+        if let Some(days) = &self.days {
+            write!(f, "{days}D")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DurationTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(hours) = &self.hours {
+            write!(f, "{hours}H")?;
+        }
+        if let Some(minutes) = &self.minutes {
+            write!(f, "{minutes}M")?;
+        }
+        if let Some(seconds) = &self.seconds {
+            write!(f, "{seconds}S")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BooleanLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.value.as_str())
+    }
+}
+
+impl fmt::Display for SignedIntegerLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(sign) = &self.sign {
+            fmt::Display::fmt(sign, f)?;
+        }
+        f.write_str(self.integral.as_str())
+    }
+}
+
+impl fmt::Display for SignedDecimalLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(sign) = &self.sign {
+            fmt::Display::fmt(sign, f)?;
+        }
+        f.write_str(self.decimal.as_str())
+    }
+}
+
+impl fmt::Display for DateLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.date, f)
+    }
+}
+
+impl fmt::Display for DateTimeLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", &self.date, &self.time)
+    }
+}
+
+impl fmt::Display for DateTimeTZLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", &self.date, &self.time)?;
+        match &self.timezone {
+            TimeZone::IANA(name) => write!(f, " {name}"),
+            timezone => write!(f, "{timezone}"),
+        }
+    }
+}
+
+impl fmt::Display for DurationLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("P")?;
+        match self {
+            DurationLiteral::Weeks(weeks) => write!(f, "{weeks}W")?,
+            DurationLiteral::DateAndTime(date, time) => {
+                fmt::Display::fmt(date, f)?;
+                match time {
+                    None => {}
+                    Some(time) => write!(f, "T{time}")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for StructLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.inner.as_str())
+    }
+}
+
+impl fmt::Display for NullLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.value.as_str())
+    }
+}
+
+impl RangeLiteral {
+    pub(crate) fn separator(&self) -> &'static str {
+        if self.inclusive_end {
+            ".."
+        } else {
+            "..<"
+        }
+    }
+}
+
+impl fmt::Display for RangeLiteral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.start, self.separator(), self.end)
+    }
+}
+
+fn negated_sign(sign: Option<Sign>, is_zero: bool) -> Option<Sign> {
+    match sign {
+        _ if is_zero => None,
+        Some(Sign::Minus) => None,
+        None | Some(Sign::Plus) => Some(Sign::Minus),
+    }
+}
+
+impl Sign {
+    /// Applies the sign to a value parsed from a literal's magnitude: `sign.apply(magnitude.parse()?)`.
+    pub fn apply<T: Neg<Output = T>>(self, value: T) -> T {
+        match self {
+            Sign::Plus => value,
+            Sign::Minus => -value,
+        }
+    }
+}
+
+impl TimeFragment {
+    pub fn is_leap_second(&self) -> bool {
+        self.second.as_deref() == Some("60")
+    }
+
+    /// The number of fractional second digits as written, including trailing zeros, or `0` without a fraction.
+    pub fn fraction_precision(&self) -> usize {
+        self.second_fraction.as_ref().map_or(0, String::len)
+    }
+
+    /// The second fraction digits without trailing zeros, so `100` becomes `1`. A fraction of only zeros, such as
+    /// `.0`, is `None` like an absent one; check `second_fraction` to tell them apart.
+    pub fn normalized_fraction(&self) -> Option<String> {
+        let fraction = self.second_fraction.as_deref()?.trim_end_matches('0');
+        (!fraction.is_empty()).then(|| fraction.to_owned())
+    }
+
+    /// The second fraction in nanoseconds. Digits beyond nanosecond precision are truncated.
+    pub fn fraction_nanos(&self) -> Option<u32> {
+        self.fraction_nanos_with(FractionPolicy::Truncate)
+    }
+
+    /// The second fraction in nanoseconds, with digits beyond nanosecond precision handled by `policy`. Parsed
+    /// fractions have at most nine digits, so the policy only matters for constructed fragments. Rounding `.9999999995`
+    /// up gives `1_000_000_000`, carrying into the next second, which is left to the caller.
+    pub fn fraction_nanos_with(&self, policy: FractionPolicy) -> Option<u32> {
+        let fraction = self.second_fraction.as_deref()?;
+        let digits = &fraction[..fraction.len().min(9)];
+        let nanos = digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32);
+        match (policy, fraction.as_bytes().get(9)) {
+            (FractionPolicy::RoundHalfUp, Some(b'5'..=b'9')) => Some(nanos + 1),
+            _ => Some(nanos),
+        }
+    }
+}
+
+impl DateLiteral {
+    /// The datetime at `time` on this date, e.g. to assemble a datetime from separately parsed parts. Value literals
+    /// carry no spans, so spans are left to the enclosing [`Literal`].
+    pub fn with_time(self, time: TimeFragment) -> DateTimeLiteral {
+        DateTimeLiteral { date: self.date, time }
+    }
+}
+
+impl DateTimeLiteral {
+    /// This datetime in `timezone`, as with [`DateLiteral::with_time`].
+    pub fn with_timezone(self, timezone: TimeZone) -> DateTimeTZLiteral {
+        DateTimeTZLiteral { date: self.date, time: self.time, timezone }
+    }
+
+    /// This local datetime in `timezone`, for storage that requires a zone, making the zone assumed for a naive
+    /// datetime explicit rather than defaulting to UTC in conversion code. To keep the span of a parsed literal, apply
+    /// it with [`Literal::map_value`].
+    pub fn assume_timezone(&self, timezone: TimeZone) -> DateTimeTZLiteral {
+        self.clone().with_timezone(timezone)
+    }
+
+    /// The date and time of this datetime, e.g. to store them in separate columns. The inverse of
+    /// [`DateLiteral::with_time`].
+    pub fn split(self) -> (DateLiteral, TimeFragment) {
+        (DateLiteral { date: self.date }, self.time)
+    }
+
+    /// Whether the time has a fractional second, which storage with whole-second precision would truncate.
+    pub fn has_subsecond(&self) -> bool {
+        self.time.second_fraction.is_some()
+    }
+
+    /// The datetime without its fractional second, for storage with whole-second precision. With
+    /// [`FractionPolicy::RoundHalfUp`], a fraction of at least `.5` rounds up to the next second, carrying into the
+    /// minute, hour, day, month and year as needed: `2023-12-31T23:59:59.9` becomes `2024-01-01T00:00:00`. A leap
+    /// second is kept as second `60`, unless its fraction rounds up, when it becomes the start of the next minute.
+    pub fn truncate_to_seconds(&self, policy: FractionPolicy) -> DateTimeLiteral {
+        let time = TimeFragment { second_fraction: None, ..self.time.clone() };
+        let first_digit = self.time.second_fraction.as_deref().and_then(|fraction| fraction.bytes().next());
+        if policy == FractionPolicy::Truncate || !matches!(first_digit, Some(b'5'..=b'9')) {
+            return DateTimeLiteral { date: self.date.clone(), time };
+        }
+
+        let component = |value: &str| value.parse::<u32>().expect("time components are digits");
+        let (mut hour, mut minute) = (component(&time.hour), component(&time.minute));
+        let mut second = time.second.as_deref().map_or(0, component) + 1;
+        let mut date = self.date.clone();
+        if second >= 60 {
+            second = 0;
+            minute += 1;
+        }
+        if minute >= 60 {
+            minute = 0;
+            hour += 1;
+        }
+        if hour >= 24 {
+            hour = 0;
+            date = date.next_day();
+        }
+        let time = TimeFragment {
+            hour: format!("{hour:02}"),
+            minute: format!("{minute:02}"),
+            second: Some(format!("{second:02}")),
+            second_fraction: None,
+        };
+        DateTimeLiteral { date, time }
+    }
+}
+
+impl DateTimeTZLiteral {
+    /// The date, time and time zone of this datetime, as with [`DateTimeLiteral::split`].
+    pub fn split(self) -> (DateLiteral, TimeFragment, TimeZone) {
+        (DateLiteral { date: self.date }, self.time, self.timezone)
+    }
+
+    /// Whether the time has a fractional second, as with [`DateTimeLiteral::has_subsecond`].
+    pub fn has_subsecond(&self) -> bool {
+        self.time.second_fraction.is_some()
+    }
+}
+
+impl DateFragment {
+    /// The month, or `01` if omitted from a partial date.
+    pub fn month_or_first(&self) -> &str {
+        self.month.as_deref().unwrap_or("01")
+    }
+
+    /// The day of the month, or `01` if omitted from a partial date.
+    pub fn day_or_first(&self) -> &str {
+        self.day.as_deref().unwrap_or("01")
+    }
+
+    /// The date with a two-digit year expanded to four digits: years below `pivot` to `20xx`, and others to `19xx`.
+    /// With a pivot of `30`, `29` becomes `2029` and `30` becomes `1930`. Any other year is kept as written.
+    pub fn expand_year(&self, pivot: u16) -> DateFragment {
+        let year = match self.year.parse::<u16>() {
+            Ok(year) if self.year.len() == 2 && year < pivot => format!("20{}", self.year),
+            Ok(_) if self.year.len() == 2 => format!("19{}", self.year),
+            _ => self.year.clone(),
+        };
+        DateFragment { year, ..self.clone() }
+    }
+
+    /// The following calendar day, rolling over into the next month and year, with the year written as in canonical
+    /// form.
+    fn next_day(&self) -> DateFragment {
+        let component = |value: &str| value.parse::<u32>().expect("date components are digits");
+        let mut year: i64 = self.year.parse().expect("years are digits");
+        let (mut month, mut day) = (component(self.month_or_first()), component(self.day_or_first()) + 1);
+        if day > validation::days_in_month(year, month) {
+            day = 1;
+            month += 1;
+        }
+        if month > 12 {
+            month = 1;
+            year += 1;
+        }
+        let year = if (0..=9999).contains(&year) { format!("{year:04}") } else { format!("{year:+05}") };
+        DateFragment { year, month: Some(format!("{month:02}")), day: Some(format!("{day:02}")) }
+    }
+}
+
+impl TimeZone {
+    /// The UTC offset in seconds of `Z` or an ISO 8601 offset (`±HH`, `±HHMM` or `±HH:MM`), or `None` for an IANA
+    /// zone, whose offset depends on the date.
+    pub fn iso_offset_seconds(&self) -> Option<i32> {
+        let offset = match self {
+            TimeZone::IANA(_) => return None,
+            TimeZone::ISO(offset) => offset,
+            TimeZone::Utc => return Some(0),
+        };
+        let (sign, hours_minutes) = offset.split_at(1);
+        let digits = hours_minutes.replace(':', "");
+        let hours: i32 = digits.get(0..2)?.parse().ok()?;
+        let minutes: i32 = if digits.len() > 2 { digits.get(2..)?.parse().ok()? } else { 0 };
+        let seconds = hours * 3600 + minutes * 60;
+        Some(if sign == "-" { -seconds } else { seconds })
+    }
+}
+
+impl IntegerLiteral {
+    /// The number of significant digits: all digits after any leading zeros, so `100` has three. Zero has one.
+    pub fn significant_digits(&self) -> usize {
+        significant_digits(&self.value)
+    }
+}
+
+impl SignedIntegerLiteral {
+    /// The digits of the integer, without its sign.
+    pub fn magnitude(&self) -> &str {
+        &self.integral
+    }
+
+    /// Whether the integer is below zero, from its sign alone: `-0` is not negative.
+    pub fn is_negative(&self) -> bool {
+        self.sign == Some(Sign::Minus) && self.integral.bytes().any(|digit| digit != b'0')
+    }
+
+    /// The integer with the opposite sign and the digits as written: `5` and `+5` become `-5`, `-5` becomes `5`, and
+    /// zero is always unsigned.
+    pub fn negated(&self) -> SignedIntegerLiteral {
+        let is_zero = self.integral.bytes().all(|digit| digit == b'0');
+        SignedIntegerLiteral { sign: negated_sign(self.sign, is_zero), integral: self.integral.clone() }
+    }
+
+    /// Whether the integer is even, from its last digit alone.
+    pub fn is_even(&self) -> bool {
+        self.integral.bytes().last().is_some_and(|digit| (digit - b'0') % 2 == 0)
+    }
+
+    /// The sum as a canonical literal, or [`IntegerOutOfRange`] if either integer or the sum is outside the range of
+    /// an `i128`.
+    ///
+    /// [`IntegerOutOfRange`]: TypeQLError::IntegerOutOfRange
+    pub fn checked_add(&self, other: &Self) -> Result<SignedIntegerLiteral> {
+        let sum = self.as_i128()?.checked_add(other.as_i128()?);
+        let out_of_range = || TypeQLError::IntegerOutOfRange { integer: format!("{self} + {other}"), source: None };
+        Ok(Self::from_i128(sum.ok_or_else(out_of_range)?))
+    }
+
+    /// The difference as a canonical literal, as with [`checked_add`](Self::checked_add).
+    pub fn checked_sub(&self, other: &Self) -> Result<SignedIntegerLiteral> {
+        let difference = self.as_i128()?.checked_sub(other.as_i128()?);
+        let out_of_range = || TypeQLError::IntegerOutOfRange { integer: format!("{self} - {other}"), source: None };
+        Ok(Self::from_i128(difference.ok_or_else(out_of_range)?))
+    }
+
+    fn as_i128(&self) -> Result<i128> {
+        let integer = self.to_string();
+        Ok(integer.parse().map_err(|source| TypeQLError::IntegerOutOfRange { integer, source: Some(source) })?)
+    }
+
+    fn from_i128(integer: i128) -> Self {
+        let sign = (integer < 0).then_some(Sign::Minus);
+        SignedIntegerLiteral { sign, integral: integer.unsigned_abs().to_string() }
+    }
+}
+
+impl SignedDecimalLiteral {
+    /// The decimal without its sign, including any exponent.
+    pub fn magnitude(&self) -> &str {
+        &self.decimal
+    }
+
+    /// The decimal with the opposite sign, as [`SignedIntegerLiteral::negated`] negates integers.
+    pub fn negated(&self) -> SignedDecimalLiteral {
+        let is_zero = Normalised::new(None, &self.decimal).digits.is_empty();
+        SignedDecimalLiteral { sign: negated_sign(self.sign, is_zero), decimal: self.decimal.clone() }
+    }
+
+    /// The digits and point of the decimal, without its sign or exponent: `1.5` in `-1.5e10`.
+    pub fn mantissa(&self) -> &str {
+        self.decimal.split_once(['e', 'E']).map_or(self.decimal.as_str(), |(mantissa, _)| mantissa)
+    }
+
+    /// The exponent of a decimal in scientific notation, `10` in `1.5e10`, or `None` without an exponent.
+    pub fn exponent(&self) -> Result<Option<i32>> {
+        let Some((_, exponent)) = self.decimal.split_once(['e', 'E']) else { return Ok(None) };
+        match exponent.parse() {
+            Ok(exponent) => Ok(Some(exponent)),
+            Err(source) => {
+                Err(TypeQLError::ExponentOutOfRange { decimal: self.to_string(), source: Some(source) }.into())
+            }
+        }
+    }
+
+    /// The number of significant digits of the mantissa, ignoring the sign, the point and leading zeros. Trailing
+    /// zeros are significant, so `0.00120` has three and `100.0` has four. Zero, such as `0.0`, has one.
+    pub fn significant_digits(&self) -> usize {
+        significant_digits(&self.mantissa().replace('.', ""))
+    }
+
+    /// Whether the decimal is in the plain form `digits.digits`, without a sign or exponent, e.g. to take a fast path
+    /// that only handles that form and leave other decimals to a full parser.
+    pub fn is_simple(&self) -> bool {
+        self.sign.is_none()
+            && self.decimal.split_once('.').is_some_and(|(integral, fraction)| {
+                [integral, fraction]
+                    .iter()
+                    .all(|digits| !digits.is_empty() && digits.bytes().all(|digit| digit.is_ascii_digit()))
+            })
+    }
+
+    /// The nearest `f64` to the decimal, for APIs that only take floats, and whether the conversion was exact. Most
+    /// decimal fractions, like `0.1`, have no exact float, and decimals too large for a float become infinite.
+    pub fn as_f64_lossy(&self) -> (f64, bool) {
+        let float: f64 = self.to_string().parse().expect("decimal literals are valid floats");
+        let is_exact = float.is_finite() && Normalised::from_f64(float) == Normalised::new(self.sign, self.magnitude());
+        (float, is_exact)
+    }
+
+    /// Removes redundant trailing zeros from the fractional part, e.g. `1.500` becomes `1.5` and `100.00` becomes
+    /// `100.0`. One fractional digit is always kept, as a decimal literal requires digits after the point.
+    /// Any exponent is preserved as written.
+    pub fn trim_trailing_zeros(&self) -> SignedDecimalLiteral {
+        let magnitude = self.magnitude();
+        let (mantissa, exponent) = match magnitude.find(['e', 'E']) {
+            Some(index) => magnitude.split_at(index),
+            None => (magnitude, ""),
+        };
+        let point = mantissa.find('.').map_or(mantissa.len(), |point| point + 1);
+        let (integral, fraction) = mantissa.split_at(point);
+        let fraction = match fraction.trim_end_matches('0') {
+            "" if !fraction.is_empty() => "0",
+            trimmed => trimmed,
+        };
+        SignedDecimalLiteral { sign: self.sign, decimal: format!("{integral}{fraction}{exponent}") }
+    }
+
+    /// The decimal in the fewest characters, for space-constrained output such as logs: without a `+` sign, leading
+    /// zeros or trailing fractional zeros, so `0.5` is written `.5` and `1.0` is written `1`. Zero is written `0`,
+    /// and any exponent is kept as written. The result is not a TypeQL literal, which needs digits around the point.
+    pub fn to_compact_string(&self) -> String {
+        let mantissa = self.mantissa();
+        let exponent = &self.decimal[mantissa.len()..];
+        let (integral, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let (integral, fraction) = (integral.trim_start_matches('0'), fraction.trim_end_matches('0'));
+        match (integral, fraction) {
+            ("", "") => "0".to_owned(),
+            _ => {
+                let sign = if self.sign == Some(Sign::Minus) { "-" } else { "" };
+                let point = if fraction.is_empty() { "" } else { "." };
+                format!("{sign}{integral}{point}{fraction}{exponent}")
+            }
+        }
+    }
+}
+
+impl StringLiteral {
+    /// Whether this is a triple-quoted `"""..."""` block, whose content is taken verbatim, without escape sequences.
+    pub fn is_block(&self) -> bool {
+        self.value.len() >= 2 * BLOCK_QUOTE.len()
+            && self.value.starts_with(BLOCK_QUOTE)
+            && self.value.ends_with(BLOCK_QUOTE)
+    }
+
+    pub fn unescape(&self) -> Result<String> {
+        Ok(self.unescaped_chars().collect::<std::result::Result<_, _>>()?)
+    }
+
+    /// The characters of [`unescape`](Self::unescape), with escape sequences decoded lazily so that a scan can stop
+    /// early without building the whole string. An invalid escape yields an error and ends the iteration.
+    pub fn unescaped_chars(&self) -> impl Iterator<Item = std::result::Result<char, TypeQLError>> + '_ {
+        let (escaped_string, is_block) = if self.is_block() {
+            (&self.value[BLOCK_QUOTE.len()..self.value.len() - BLOCK_QUOTE.len()], true)
+        } else {
+            let bytes = self.value.as_bytes();
+            // it's a bug if these fail; either in the parser or the builder
+            assert!(bytes.len() >= 2, "string literal {:?} is missing its quotes", self.value);
+            assert_eq!(bytes[0], bytes[bytes.len() - 1]);
+            assert!(matches!(bytes[0], b'\'' | b'"'));
+            (&self.value[1..self.value.len() - 1], false)
+        };
+
+        let mut rest = escaped_string;
+        std::iter::from_fn(move || {
+            let char = rest.chars().next()?;
+            let unescaped = if is_block || char != '\\' {
+                Ok((char, char.len_utf8()))
+            } else {
+                unescape_sequence(escaped_string, rest)
+            };
+            match unescaped {
+                Ok((char, escaped_len)) => {
+                    rest = &rest[escaped_len..];
+                    Some(Ok(char))
+                }
+                Err(error) => {
+                    rest = "";
+                    Some(Err(error))
+                }
+            }
+        })
+    }
+
+    /// A double-quoted literal of `content`, escaping the quote and backslash, and with [`EscapePolicy::AsciiOnly`]
+    /// also every non-ASCII character as `\u{...}`.
+    pub fn build(content: &str, policy: EscapePolicy) -> StringLiteral {
+        let mut value = String::with_capacity(content.len() + 2);
+        value.push('"');
+        for char in content.chars() {
+            match char {
+                '"' | '\\' => {
+                    value.push('\\');
+                    value.push(char);
+                }
+                char if !char.is_ascii() && policy == EscapePolicy::AsciiOnly => {
+                    value.push_str(&format!(r"\u{{{:x}}}", char as u32));
+                }
+                char => value.push(char),
+            }
+        }
+        value.push('"');
+        StringLiteral { value }
+    }
+
+    /// The string re-quoted with double quotes regardless of how it was written, e.g. for output to a system that only
+    /// accepts double-quoted strings: `'he said "hi"'` becomes `"he said \"hi\""`, whereas `Display` keeps the
+    /// original quote style.
+    pub fn to_double_quoted(&self) -> Result<String> {
+        let mut quoted = String::from('"');
+        for char in self.unescape()?.chars() {
+            match char {
+                '"' => quoted.push_str(r#"\""#),
+                '\\' => quoted.push_str(r"\\"),
+                '\x08' => quoted.push_str(r"\b"),
+                '\t' => quoted.push_str(r"\t"),
+                '\n' => quoted.push_str(r"\n"),
+                '\x0c' => quoted.push_str(r"\f"),
+                '\r' => quoted.push_str(r"\r"),
+                char => quoted.push(char),
+            }
+        }
+        quoted.push('"');
+        Ok(quoted)
+    }
+}
+
+fn significant_digits(digits: &str) -> usize {
+    digits.trim_start_matches('0').len().max(1)
+}
+
+/// The character and escape length of the escape sequence at the start of `rest`, part of `escaped_string`.
+fn unescape_sequence(escaped_string: &str, rest: &str) -> std::result::Result<(char, usize), TypeQLError> {
+    let bytes = rest.as_bytes();
+
+    if bytes.len() < 2 {
+        return Err(TypeQLError::InvalidStringEscape {
+            full_string: escaped_string.to_owned(),
+            escape: String::from(r"\"),
+        });
+    }
+
+    match bytes[1] {
+        BSP => Ok(('\x08', 2)),
+        TAB => Ok(('\x09', 2)),
+        LF_ => Ok(('\x0a', 2)),
+        FF_ => Ok(('\x0c', 2)),
+        CR_ => Ok(('\x0d', 2)),
+        c @ (b'"' | b'\'' | b'\\') => Ok((c as char, 2)),
+        b'u' => unescape_unicode(rest).ok_or_else(|| TypeQLError::InvalidStringEscape {
+            full_string: escaped_string.to_owned(),
+            escape: String::from(r"\u"),
+        }),
+        _ => Err(TypeQLError::InvalidStringEscape {
+            full_string: escaped_string.to_owned(),
+            escape: format!(r"\{}", rest.chars().nth(1).unwrap()),
+        }),
+    }
+}
+
+/// The character and escape length of a `\u{...}` escape of one to six hex digits at the start of `escape`.
+fn unescape_unicode(escape: &str) -> Option<(char, usize)> {
+    let (hex, _) = escape.strip_prefix(r"\u{")?.split_once('}')?;
+    if !(1..=6).contains(&hex.len()) || !hex.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        return None;
+    }
+    let char = char::from_u32(u32::from_str_radix(hex, 16).ok()?)?;
+    Some((char, hex.len() + 4))
+}
+
+const BLOCK_QUOTE: &str = "\"\"\"";
+
+const BSP: u8 = b'b';
+const TAB: u8 = b't';
+const LF_: u8 = b'n';
+const FF_: u8 = b'f';
+const CR_: u8 = b'r';
@@ -0,0 +1,59 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions of literals to native values, checking the literal's type. Each has an `_or` variant that falls back
+//! to a default, for configuration-style code where any invalid value means "use the default".
+
+use crate::{
+    common::{error::TypeQLError, Spanned},
+    value::{Literal, ValueType},
+    Result,
+};
+
+impl Literal {
+    /// The integer as an `i64`, rejecting other types with [`ValueTypeMismatch`](TypeQLError::ValueTypeMismatch) and
+    /// integers outside the range of an `i64` with [`NumberOutOfRange`](TypeQLError::NumberOutOfRange).
+    pub fn as_i64(&self) -> Result<i64> {
+        // the sign is parsed with the magnitude, so that `i64::MIN` does not overflow as a positive magnitude
+        let number = self.expect_type(ValueType::Integer)?.to_string();
+        let out_of_range =
+            || TypeQLError::NumberOutOfRange { number: number.clone(), target: "an i64", span: self.span() };
+        Ok(number.parse().map_err(|_| out_of_range())?)
+    }
+
+    /// The integer as an `i64`, or `default` where [`as_i64`](Self::as_i64) fails.
+    pub fn as_i64_or(&self, default: i64) -> i64 {
+        self.as_i64().unwrap_or(default)
+    }
+
+    /// The decimal as the nearest `f64`, as [`as_f64_lossy`](crate::value::SignedDecimalLiteral::as_f64_lossy)
+    /// converts it, rejecting other types with [`ValueTypeMismatch`](TypeQLError::ValueTypeMismatch) and decimals too
+    /// large for a float with [`NumberOutOfRange`](TypeQLError::NumberOutOfRange) rather than making them infinite.
+    pub fn as_f64(&self) -> Result<f64> {
+        self.expect_type(ValueType::Decimal)?;
+        let (float, _) = self.as_decimal().expect("the literal is a decimal").as_f64_lossy();
+        if !float.is_finite() {
+            Err(TypeQLError::NumberOutOfRange { number: self.to_string(), target: "an f64", span: self.span() })?
+        }
+        Ok(float)
+    }
+
+    /// The decimal as an `f64`, or `default` where [`as_f64`](Self::as_f64) fails.
+    pub fn as_f64_or(&self, default: f64) -> f64 {
+        self.as_f64().unwrap_or(default)
+    }
+
+    /// The boolean, rejecting other types with [`ValueTypeMismatch`](TypeQLError::ValueTypeMismatch).
+    pub fn as_bool(&self) -> Result<bool> {
+        self.expect_type(ValueType::Boolean)?;
+        Ok(self.as_boolean().expect("the literal is a boolean").value.eq_ignore_ascii_case("true"))
+    }
+
+    /// The boolean, or `default` where [`as_bool`](Self::as_bool) fails.
+    pub fn as_bool_or(&self, default: bool) -> bool {
+        self.as_bool().unwrap_or(default)
+    }
+}
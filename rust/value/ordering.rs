@@ -0,0 +1,349 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cmp::Ordering;
+
+use crate::value::{
+    DateFragment, DateTimeLiteral, DateTimeTZLiteral, Literal, Sign, SignedDecimalLiteral, StringLiteral, TimeFragment,
+    ValueLiteral,
+};
+
+impl Literal {
+    /// Orders literals of any type, first by [`ValueType`](crate::value::ValueType) precedence and then by value:
+    /// - numbers by numeric value regardless of notation, so `1.50`, `1.5` and `15.0e-1` are equal
+    /// - strings by their escaped content, regardless of the quotes used
+    /// - dates and times chronologically, with datetime-tz values compared by local time and then by time zone
+    /// - durations, which mix calendar and clock units and have no natural order, and structs by their text
+    /// - ranges by their start, then by their end, with an excluded end first
+    /// - nulls are all equal, whichever null token they were written with
+    pub fn total_cmp(&self, other: &Literal) -> Ordering {
+        self.inner.total_cmp(&other.inner)
+    }
+
+    /// Bytes whose lexicographic order is the order of [`total_cmp`](Self::total_cmp), e.g. as the key of an on-disk
+    /// index. Literals that compare equal have equal keys, and no key is a prefix of another.
+    pub fn order_preserving_key(&self) -> Vec<u8> {
+        let mut key = Vec::new();
+        self.inner.write_order_key(&mut key);
+        key
+    }
+}
+
+impl ValueLiteral {
+    pub fn total_cmp(&self, other: &ValueLiteral) -> Ordering {
+        match (self, other) {
+            (Self::Null(_), Self::Null(_)) => Ordering::Equal,
+            (Self::Boolean(lhs), Self::Boolean(rhs)) => (lhs.value == "true").cmp(&(rhs.value == "true")),
+            (Self::Integer(lhs), Self::Integer(rhs)) => {
+                cmp_numeric(lhs.sign, lhs.magnitude(), rhs.sign, rhs.magnitude())
+            }
+            (Self::Decimal(lhs), Self::Decimal(rhs)) => {
+                cmp_numeric(lhs.sign, lhs.magnitude(), rhs.sign, rhs.magnitude())
+            }
+            (Self::String(lhs), Self::String(rhs)) => string_content(lhs).cmp(string_content(rhs)),
+            (Self::Date(lhs), Self::Date(rhs)) => date_key(&lhs.date).cmp(&date_key(&rhs.date)),
+            (Self::DateTime(lhs), Self::DateTime(rhs)) => date_key(&lhs.date)
+                .cmp(&date_key(&rhs.date))
+                .then_with(|| time_key(&lhs.time).cmp(&time_key(&rhs.time))),
+            (Self::DateTimeTz(lhs), Self::DateTimeTz(rhs)) => date_key(&lhs.date)
+                .cmp(&date_key(&rhs.date))
+                .then_with(|| time_key(&lhs.time).cmp(&time_key(&rhs.time)))
+                .then_with(|| lhs.timezone.to_string().cmp(&rhs.timezone.to_string())),
+            (Self::Range(lhs), Self::Range(rhs)) => lhs
+                .start
+                .total_cmp(&rhs.start)
+                .then_with(|| lhs.end.total_cmp(&rhs.end))
+                .then_with(|| lhs.inclusive_end.cmp(&rhs.inclusive_end)),
+            (lhs, rhs) if lhs.value_type() == rhs.value_type() => lhs.to_string().cmp(&rhs.to_string()),
+            (lhs, rhs) => lhs.value_type().cmp(&rhs.value_type()),
+        }
+    }
+}
+
+impl ValueLiteral {
+    fn write_order_key(&self, key: &mut Vec<u8>) {
+        key.push(self.value_type() as u8);
+        match self {
+            Self::Null(_) => (),
+            Self::Boolean(boolean) => key.push(u8::from(boolean.value == "true")),
+            Self::Integer(integer) => write_numeric_key(key, &Normalised::new(integer.sign, integer.magnitude())),
+            Self::Decimal(decimal) => write_numeric_key(key, &Normalised::new(decimal.sign, decimal.magnitude())),
+            Self::String(string) => write_string_key(key, string_content(string)),
+            Self::Date(date) => write_date_key(key, &date.date),
+            Self::DateTime(date_time) => {
+                write_date_key(key, &date_time.date);
+                write_time_key(key, &date_time.time);
+            }
+            Self::DateTimeTz(date_time) => {
+                write_date_key(key, &date_time.date);
+                write_time_key(key, &date_time.time);
+                write_string_key(key, &date_time.timezone.to_string());
+            }
+            Self::Range(range) => {
+                range.start.inner.write_order_key(key);
+                range.end.inner.write_order_key(key);
+                key.push(u8::from(range.inclusive_end));
+            }
+            Self::Duration(_) | Self::Struct(_) | Self::TimeZone(_) => write_string_key(key, &self.to_string()),
+        }
+    }
+}
+
+impl SignedDecimalLiteral {
+    /// Compares decimals by value from their text alone: by sign, then by the length and digits of the integral part
+    /// without leading zeros, then by the fractional digits without trailing zeros, so `1.5` and `1.50` are equal. This
+    /// is cheaper than [`total_cmp`](Literal::total_cmp), but assumes decimals without an exponent: `1.5e3` is not
+    /// ordered correctly.
+    pub fn cmp_textual(&self, other: &Self) -> Ordering {
+        let (lhs, rhs) = (TextualDecimal::new(self), TextualDecimal::new(other));
+        match (lhs.is_negative, rhs.is_negative) {
+            (false, false) => lhs.cmp_magnitude(&rhs),
+            (true, true) => rhs.cmp_magnitude(&lhs),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+struct TextualDecimal<'a> {
+    is_negative: bool,
+    integral: &'a str,
+    fraction: &'a str,
+}
+
+impl<'a> TextualDecimal<'a> {
+    fn new(decimal: &'a SignedDecimalLiteral) -> Self {
+        let (integral, fraction) = decimal.magnitude().split_once('.').unwrap_or((decimal.magnitude(), ""));
+        let (integral, fraction) = (integral.trim_start_matches('0'), fraction.trim_end_matches('0'));
+        let is_zero = integral.is_empty() && fraction.is_empty();
+        Self { is_negative: decimal.sign == Some(Sign::Minus) && !is_zero, integral, fraction }
+    }
+
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        (self.integral.len(), self.integral, self.fraction).cmp(&(other.integral.len(), other.integral, other.fraction))
+    }
+}
+
+/// Dates order chronologically, comparing the numeric year, month and day, so `2023-12-31` sorts before `2024-01-01`.
+/// Dates that are the same day but written differently, like the partial date `2024-01` and `2024-01-01`, are then
+/// ordered by their text, so that the order agrees with `Eq` and the fragments can be used as `BTreeMap` keys.
+impl Ord for DateFragment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        date_key(self)
+            .cmp(&date_key(other))
+            .then_with(|| (&self.year, &self.month, &self.day).cmp(&(&other.year, &other.month, &other.day)))
+    }
+}
+
+impl PartialOrd for DateFragment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Times order chronologically, as with dates, with equal times written differently, like `10:00` and `10:00:00`,
+/// ordered by their text.
+impl Ord for TimeFragment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        time_key(self).cmp(&time_key(other)).then_with(|| {
+            (&self.hour, &self.minute, &self.second, &self.second_fraction).cmp(&(
+                &other.hour,
+                &other.minute,
+                &other.second,
+                &other.second_fraction,
+            ))
+        })
+    }
+}
+
+impl PartialOrd for TimeFragment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Datetimes without a time zone order by date and then by time.
+impl Ord for DateTimeLiteral {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date.cmp(&other.date).then_with(|| self.time.cmp(&other.time))
+    }
+}
+
+impl PartialOrd for DateTimeLiteral {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Datetimes with a time zone are zone-naive: ordered by local date and time, and then by the time zone's text, as by
+/// [`Literal::total_cmp`], so `2024-01-01T10:00+02:00` sorts after `2024-01-01T09:00Z`. With the `chrono-tz` feature,
+/// `DateTimeTZLiteral::cmp_instant` compares the instants they denote instead.
+impl Ord for DateTimeTZLiteral {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date
+            .cmp(&other.date)
+            .then_with(|| self.time.cmp(&other.time))
+            .then_with(|| self.timezone.to_string().cmp(&other.timezone.to_string()))
+    }
+}
+
+impl PartialOrd for DateTimeTZLiteral {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A finite number in the form `0.digits * 10^exponent`, where `digits` has no leading or trailing zeros.
+/// Zero is represented by empty `digits`. Exponents beyond the range of an `i64` saturate, so such numbers still order
+/// above or below all others, though not among themselves.
+#[derive(Debug, PartialEq)]
+pub(super) struct Normalised {
+    pub(super) is_negative: bool,
+    pub(super) digits: String,
+    pub(super) exponent: i64,
+}
+
+impl Normalised {
+    pub(super) fn new(sign: Option<Sign>, text: &str) -> Self {
+        let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, saturating_exponent(exponent)),
+            None => (text, 0),
+        };
+        let (integral, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits = format!("{integral}{fractional}");
+        let leading_zeros = digits.len() - digits.trim_start_matches('0').len();
+        let digits = digits.trim_matches('0').to_owned();
+        let point = integral.len() as i64 - leading_zeros as i64;
+        let exponent = if digits.is_empty() { 0 } else { point.saturating_add(exponent) };
+        Self { is_negative: sign == Some(Sign::Minus) && !digits.is_empty(), digits, exponent }
+    }
+
+    /// The exact value of a finite float. Floats are binary fractions `mantissa * 2^exponent`, so always have a
+    /// finite decimal expansion: `mantissa * 2^exponent` when the exponent is positive, and
+    /// `mantissa * 5^-exponent * 10^exponent` when it is negative.
+    pub(super) fn from_f64(float: f64) -> Self {
+        debug_assert!(float.is_finite());
+        let bits = float.to_bits();
+        let (biased_exponent, fraction) = ((bits >> 52) & 0x7ff, bits & ((1 << 52) - 1));
+        let (mantissa, exponent) = match biased_exponent {
+            0 => (fraction, -1074),
+            _ => (fraction | 1 << 52, biased_exponent as i64 - 1075),
+        };
+        // little-endian decimal digits
+        let mut digits: Vec<u32> = mantissa.to_string().bytes().rev().map(|digit| (digit - b'0') as u32).collect();
+        let factor = if exponent < 0 { 5 } else { 2 };
+        for _ in 0..exponent.unsigned_abs() {
+            let mut carry = 0;
+            for digit in &mut digits {
+                let product = *digit * factor + carry;
+                (*digit, carry) = (product % 10, product / 10);
+            }
+            if carry > 0 {
+                digits.push(carry);
+            }
+        }
+        let digits: String = digits.iter().rev().map(|&digit| char::from(b'0' + digit as u8)).collect();
+        let sign = float.is_sign_negative().then_some(Sign::Minus);
+        Self::new(sign, &format!("{digits}e{}", exponent.min(0)))
+    }
+
+    /// Whether the exponent was clamped to the range of an `i64`, so that this is not the exact value.
+    pub(super) fn is_saturated(&self) -> bool {
+        matches!(self.exponent, i64::MIN | i64::MAX)
+    }
+
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        match (self.digits.is_empty(), other.digits.is_empty()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.exponent.cmp(&other.exponent).then_with(|| self.digits.cmp(&other.digits)),
+        }
+    }
+}
+
+/// The exponent, which the grammar limits to an optionally signed string of digits, clamped to the range of an `i64`.
+fn saturating_exponent(exponent: &str) -> i64 {
+    exponent.parse().unwrap_or(if exponent.starts_with('-') { i64::MIN } else { i64::MAX })
+}
+
+fn cmp_numeric(lhs_sign: Option<Sign>, lhs: &str, rhs_sign: Option<Sign>, rhs: &str) -> Ordering {
+    let (lhs, rhs) = (Normalised::new(lhs_sign, lhs), Normalised::new(rhs_sign, rhs));
+    match (lhs.is_negative, rhs.is_negative) {
+        (false, false) => lhs.cmp_magnitude(&rhs),
+        (true, true) => rhs.cmp_magnitude(&lhs),
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    }
+}
+
+fn string_content(string: &StringLiteral) -> &str {
+    let value = string.value.as_str();
+    if value.len() >= 2 {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    }
+}
+
+fn date_key(date: &DateFragment) -> (i64, u32, u32) {
+    let month = date.month_or_first().parse().unwrap_or(0);
+    (date.year.parse().unwrap_or(0), month, date.day_or_first().parse().unwrap_or(0))
+}
+
+fn time_key(time: &TimeFragment) -> (u32, u32, u32, u32) {
+    let second = time.second.as_deref().map_or(0, |second| second.parse().unwrap_or(0));
+    (time.hour.parse().unwrap_or(0), time.minute.parse().unwrap_or(0), second, time.fraction_nanos().unwrap_or(0))
+}
+
+/// Zero, then positive numbers by exponent and digits, after negative numbers, whose exponent and digits are inverted
+/// so that larger magnitudes sort first. Each digit string is terminated by a byte that sorts after the digits when
+/// negative and before them when positive, so that a shorter, smaller magnitude is not a prefix of a longer one.
+fn write_numeric_key(key: &mut Vec<u8>, number: &Normalised) {
+    if number.digits.is_empty() {
+        key.push(1);
+    } else if number.is_negative {
+        key.push(0);
+        key.extend(i64_key(number.exponent).map(|byte| !byte));
+        key.extend(number.digits.bytes().map(|byte| !byte));
+        key.push(u8::MAX);
+    } else {
+        key.push(2);
+        key.extend(i64_key(number.exponent));
+        key.extend(number.digits.bytes());
+        key.push(0);
+    }
+}
+
+/// The string's bytes with every zero byte escaped as `0x00 0xFF`, terminated by `0x00 0x00`.
+fn write_string_key(key: &mut Vec<u8>, string: &str) {
+    for byte in string.bytes() {
+        key.push(byte);
+        if byte == 0 {
+            key.push(u8::MAX);
+        }
+    }
+    key.extend([0, 0]);
+}
+
+fn write_date_key(key: &mut Vec<u8>, date: &DateFragment) {
+    let (year, month, day) = date_key(date);
+    key.extend(i64_key(year));
+    key.extend(month.to_be_bytes());
+    key.extend(day.to_be_bytes());
+}
+
+fn write_time_key(key: &mut Vec<u8>, time: &TimeFragment) {
+    let (hour, minute, second, nanos) = time_key(time);
+    for part in [hour, minute, second, nanos] {
+        key.extend(part.to_be_bytes());
+    }
+}
+
+/// Big-endian with the sign bit flipped, so that negative numbers sort before positive ones.
+fn i64_key(value: i64) -> [u8; 8] {
+    ((value as u64) ^ (1 << 63)).to_be_bytes()
+}
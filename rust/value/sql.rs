@@ -0,0 +1,77 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::{
+    common::error::TypeQLError,
+    value::{Literal, TimeZone, ValueLiteral},
+    Result,
+};
+
+/// The SQL dialect that [`Literal::to_sql_literal`] writes.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum SqlDialect {
+    /// Standard SQL, with typed literals for dates and times. Durations, and datetimes in IANA time zones, cannot be
+    /// written in standard SQL.
+    #[default]
+    Generic,
+    /// PostgreSQL, which also accepts IANA time zones in timestamps and ISO 8601 durations as intervals.
+    Postgres,
+}
+
+impl fmt::Display for SqlDialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Generic => f.write_str("generic"),
+            Self::Postgres => f.write_str("Postgres"),
+        }
+    }
+}
+
+impl Literal {
+    /// The literal as a SQL literal of the equivalent type, e.g. to sync attribute values into a SQL store: numbers
+    /// and booleans in canonical form, strings single-quoted with quotes doubled, and dates and times as typed literals
+    /// such as `TIMESTAMP '2024-01-01 10:00:00'`. Time zones, ranges and structs have no SQL literal, nor do the types
+    /// the dialect does not support, and are rejected with
+    /// [`UnsupportedSqlValue`](TypeQLError::UnsupportedSqlValue).
+    pub fn to_sql_literal(&self, dialect: SqlDialect) -> Result<String> {
+        let unsupported = || TypeQLError::UnsupportedSqlValue {
+            value_type: self.value_type().to_string(),
+            dialect: dialect.to_string(),
+        };
+        let canonical = self.to_canonical_string();
+        Ok(match &self.inner {
+            ValueLiteral::Null(_) => "NULL".to_owned(),
+            ValueLiteral::Boolean(_) => canonical.to_uppercase(),
+            ValueLiteral::Integer(_) | ValueLiteral::Decimal(_) => canonical,
+            ValueLiteral::String(string) => quote(&string.unescape()?),
+            ValueLiteral::Date(_) => format!("DATE {}", quote(&canonical)),
+            ValueLiteral::DateTime(_) => format!("TIMESTAMP {}", quote(&canonical.replacen('T', " ", 1))),
+            ValueLiteral::DateTimeTz(date_time) => {
+                let timestamp = canonical.replacen('T', " ", 1);
+                // the canonical form writes every zero offset, `+00:00` included, as `Z`
+                match (dialect, &date_time.timezone, date_time.timezone.iso_offset_seconds()) {
+                    (SqlDialect::Generic, TimeZone::IANA(_), _) => Err(unsupported())?,
+                    (SqlDialect::Generic, _, Some(0)) => {
+                        format!("TIMESTAMP WITH TIME ZONE {}", quote(&timestamp.replace('Z', "+00:00")))
+                    }
+                    (SqlDialect::Generic, _, _) => format!("TIMESTAMP WITH TIME ZONE {}", quote(&timestamp)),
+                    (SqlDialect::Postgres, _, _) => format!("TIMESTAMPTZ {}", quote(&timestamp)),
+                }
+            }
+            ValueLiteral::Duration(_) if dialect == SqlDialect::Postgres => format!("INTERVAL {}", quote(&canonical)),
+            ValueLiteral::Duration(_)
+            | ValueLiteral::TimeZone(_)
+            | ValueLiteral::Range(_)
+            | ValueLiteral::Struct(_) => Err(unsupported())?,
+        })
+    }
+}
+
+fn quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
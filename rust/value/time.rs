@@ -0,0 +1,60 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions to the types of the `time` crate, for users who standardise on it rather than chrono.
+
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::{
+    common::error::TypeQLError,
+    value::{DateFragment, DateTimeTZLiteral, TimeFragment, TimeZone},
+    Result,
+};
+
+impl DateFragment {
+    pub fn as_time_date(&self) -> Result<Date> {
+        let invalid = || TypeQLError::InvalidDate { date: self.to_string() };
+        let year = self.year.parse().map_err(|_| invalid())?;
+        let month = self.month_or_first().parse::<u8>().map_err(|_| invalid())?;
+        let month = Month::try_from(month).map_err(|_| invalid())?;
+        let day = self.day_or_first().parse().map_err(|_| invalid())?;
+        Ok(Date::from_calendar_date(year, month, day).map_err(|_| invalid())?)
+    }
+}
+
+impl TimeFragment {
+    /// Unlike [`as_naive_time`](TimeFragment::as_naive_time), a leap second is an invalid time, as `time` has no
+    /// representation for one.
+    pub fn as_time_time(&self) -> Result<Time> {
+        let invalid = || TypeQLError::InvalidTime { time: self.to_string() };
+        let (hour, minute) = self.checked_hour_and_minute()?;
+        let second = match &self.second {
+            Some(second) => second.parse().map_err(|_| invalid())?,
+            None => 0,
+        };
+        let nanos = self.fraction_nanos().unwrap_or(0);
+        Ok(Time::from_hms_nano(hour as u8, minute as u8, second, nanos).map_err(|_| invalid())?)
+    }
+}
+
+impl DateTimeTZLiteral {
+    /// The datetime at its ISO offset, or at UTC. `time` cannot resolve IANA time zones, so they are rejected with
+    /// [`UnsupportedIANATimeZone`](TypeQLError::UnsupportedIANATimeZone); convert those with
+    /// [`as_utc_datetime`](DateTimeTZLiteral::as_utc_datetime) under the `chrono-tz` feature instead.
+    pub fn as_time_offset_datetime(&self) -> Result<OffsetDateTime> {
+        let offset = match &self.timezone {
+            TimeZone::Utc => UtcOffset::UTC,
+            TimeZone::ISO(offset) => {
+                let invalid = || TypeQLError::InvalidTimeZoneOffset { offset: offset.clone() };
+                let seconds = self.timezone.iso_offset_seconds().ok_or_else(invalid)?;
+                UtcOffset::from_whole_seconds(seconds).map_err(|_| invalid())?
+            }
+            TimeZone::IANA(name) => Err(TypeQLError::UnsupportedIANATimeZone { name: name.clone() })?,
+        };
+        let local = PrimitiveDateTime::new(self.date.as_time_date()?, self.time.as_time_time()?);
+        Ok(local.assume_offset(offset))
+    }
+}
@@ -0,0 +1,139 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::RangeInclusive;
+
+use crate::{
+    common::{error::TypeQLError, Span, Spanned},
+    value::{DateFragment, DateTimeLiteral, Literal, TimeFragment, ValueLiteral},
+    Result,
+};
+
+/// Controls which syntactically valid, but semantically questionable, literals pass [`Literal::validate`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ValidationOptions {
+    /// Accept a second value of `60`. See [`TimeFragment::as_naive_time`] for how leap seconds are converted.
+    pub allow_leap_seconds: bool,
+    /// The earliest year accepted in a date, for storage backends with a limited date range.
+    pub min_year: Option<i64>,
+    /// The latest year accepted in a date, for storage backends with a limited date range.
+    pub max_year: Option<i64>,
+    /// Warn about datetimes with a fractional second, for storage backends with whole-second precision. The literal
+    /// is still valid; the warning is passed to the sink of [`Literal::validate_with_sink`].
+    pub warn_on_subsecond: bool,
+    /// The most fractional second digits accepted in a time, e.g. `3` for a storage backend with millisecond
+    /// precision. Trailing zeros count, as the precision is what was written.
+    pub max_fraction_precision: Option<usize>,
+}
+
+impl Literal {
+    pub fn validate(&self, options: &ValidationOptions) -> Result {
+        self.validate_with_sink(options, |_| ())
+    }
+
+    /// Validates the literal as [`validate`](Self::validate) does, passing warnings about valid but lossy literals
+    /// to `sink`.
+    pub fn validate_with_sink(&self, options: &ValidationOptions, mut sink: impl FnMut(TypeQLError)) -> Result {
+        self.validate_into(options, &mut sink)
+    }
+
+    fn validate_into(&self, options: &ValidationOptions, sink: &mut dyn FnMut(TypeQLError)) -> Result {
+        let span = self.span();
+        match &self.inner {
+            ValueLiteral::Date(date) => validate_date(&date.date, span, options),
+            ValueLiteral::DateTime(date_time) => {
+                validate_date(&date_time.date, span, options)?;
+                validate_time(&date_time.time, span, options)?;
+                if options.warn_on_subsecond && date_time.has_subsecond() {
+                    sink(TypeQLError::SubsecondPrecision { literal: date_time.to_string(), span });
+                }
+                Ok(())
+            }
+            ValueLiteral::DateTimeTz(date_time) => {
+                validate_date(&date_time.date, span, options)?;
+                validate_time(&date_time.time, span, options)?;
+                if options.warn_on_subsecond && date_time.has_subsecond() {
+                    sink(TypeQLError::SubsecondPrecision { literal: date_time.to_string(), span });
+                }
+                Ok(())
+            }
+            ValueLiteral::Range(range) => {
+                range.start.validate_into(options, sink)?;
+                range.end.validate_into(options, sink)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl DateTimeLiteral {
+    /// Whether the date lies between `min` and `max` inclusive. The time of day is not considered.
+    pub fn is_within(&self, min: &DateFragment, max: &DateFragment) -> Result<bool> {
+        let date = date_key(&self.date)?;
+        Ok(date_key(min)? <= date && date <= date_key(max)?)
+    }
+}
+
+fn date_key(date: &DateFragment) -> Result<(i64, u32, u32)> {
+    let invalid = || TypeQLError::InvalidDate { date: date.to_string() };
+    let year = date.year.parse().map_err(|_| invalid())?;
+    let month = date.month_or_first().parse().map_err(|_| invalid())?;
+    Ok((year, month, date.day_or_first().parse().map_err(|_| invalid())?))
+}
+
+/// The value of a date or time component, rejected with [`ComponentOutOfRange`](TypeQLError::ComponentOutOfRange)
+/// outside `range`.
+pub(super) fn check_component<T: Copy + PartialOrd + Into<i64>>(
+    component: &'static str,
+    value: T,
+    range: RangeInclusive<T>,
+    span: Option<Span>,
+) -> Result<T> {
+    if !range.contains(&value) {
+        let range = (*range.start()).into()..=(*range.end()).into();
+        Err(TypeQLError::ComponentOutOfRange { component, value: value.into(), range, span })?
+    }
+    Ok(value)
+}
+
+pub(super) fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn validate_date(date: &DateFragment, span: Option<Span>, options: &ValidationOptions) -> Result {
+    let (year, month, day) = date_key(date)?;
+    check_component("month", month, 1..=12, span)?;
+    check_component("day", day, 1..=days_in_month(year, month), span)?;
+    let too_early = options.min_year.is_some_and(|min_year| year < min_year);
+    let too_late = options.max_year.is_some_and(|max_year| year > max_year);
+    if too_early || too_late {
+        Err(TypeQLError::YearOutOfRange { year: date.year.clone(), span })?
+    }
+    Ok(())
+}
+
+fn validate_time(time: &TimeFragment, span: Option<Span>, options: &ValidationOptions) -> Result {
+    let invalid = || TypeQLError::InvalidTime { time: time.to_string() };
+    check_component("hour", time.hour.parse().map_err(|_| invalid())?, 0..=23, span)?;
+    check_component("minute", time.minute.parse().map_err(|_| invalid())?, 0..=59, span)?;
+    if time.is_leap_second() && !options.allow_leap_seconds {
+        Err(TypeQLError::LeapSecondNotAllowed { span })?
+    }
+    if let Some(second) = &time.second {
+        let last_second = if options.allow_leap_seconds { 60 } else { 59 };
+        check_component("second", second.parse().map_err(|_| invalid())?, 0..=last_second, span)?;
+    }
+    let precision = time.fraction_precision();
+    if let Some(max) = options.max_fraction_precision.filter(|&max| precision > max) {
+        Err(TypeQLError::FractionTooPrecise { precision, max, span })?
+    }
+    Ok(())
+}